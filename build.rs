@@ -0,0 +1,40 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/easter.rs"]
+mod easter;
+
+/// Years covered by the lookup table of the `lookup-tables` feature.
+const TABLE_YEARS: (i32, i32) = (1990, 2100);
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/easter.rs");
+    if env::var_os("CARGO_FEATURE_LOOKUP_TABLES").is_none() {
+        return;
+    }
+    let (first, last) = TABLE_YEARS;
+    let mut table = String::new();
+    writeln!(
+        table,
+        "const EASTER_TABLE_YEARS: core::ops::RangeInclusive<i32> = {}..={};",
+        first, last
+    )
+    .unwrap();
+    writeln!(
+        table,
+        "static EASTER_SUNDAYS: [(u8, u8); {}] = [",
+        last - first + 1
+    )
+    .unwrap();
+    for year in first..=last {
+        let (month, day) = easter::gregorian(year).expect("table year out of range");
+        writeln!(table, "    ({}, {}),", month, day).unwrap();
+    }
+    table.push_str("];\n");
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("easter_table.rs"), table)
+        .expect("failed to write easter table");
+}
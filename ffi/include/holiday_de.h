@@ -0,0 +1,70 @@
+#ifndef HOLIDAY_DE_H
+#define HOLIDAY_DE_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Returned by all functions on invalid input, e.g. an unknown region or a null pointer.
+#define HOLIDAY_DE_ERROR -1
+
+// A public holiday on a specific date.
+typedef struct HolidayDeHoliday {
+  int32_t year;
+  uint32_t month;
+  uint32_t day;
+  // Identifier of the holiday, pass it to `holiday_de_holiday_name` to get its name.
+  uint32_t holiday;
+} HolidayDeHoliday;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns 1 if the given date is a public holiday in the given region, 0 if it is not
+// and `HOLIDAY_DE_ERROR` if the date or region is invalid.
+//
+// # Safety
+//
+// `region_code` must be null or point to a NUL-terminated string.
+int32_t holiday_de_is_holiday(int32_t year, uint32_t month, uint32_t day, const char *region_code);
+
+// Writes the public holidays of the given region in the given year, sorted by date,
+// into the buffer `out` with room for `capacity` entries.
+//
+// Returns the total number of holidays, which may exceed `capacity`
+// in which case only the first `capacity` holidays are written,
+// or `HOLIDAY_DE_ERROR` if the region is invalid.
+// Passing a null buffer with a capacity of 0 can be used to query the required capacity.
+//
+// # Safety
+//
+// `region_code` must be null or point to a NUL-terminated string,
+// `out` must be valid for writing `capacity` entries.
+int32_t holiday_de_holidays_in_year(const char *region_code,
+                                    int32_t year,
+                                    struct HolidayDeHoliday *out,
+                                    size_t capacity);
+
+// Writes the German name of a holiday as a NUL-terminated UTF-8 string
+// into the buffer `out` of size `len`.
+//
+// Returns the length of the name in bytes without the terminating NUL,
+// or `HOLIDAY_DE_ERROR` if the holiday is unknown.
+// If the buffer is too small, the name is truncated at a character boundary,
+// so the buffer always contains valid UTF-8.
+//
+// # Safety
+//
+// `out` must be null or valid for writing `len` bytes.
+int32_t holiday_de_holiday_name(uint32_t holiday, char *out, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HOLIDAY_DE_H */
@@ -0,0 +1,235 @@
+//! C bindings for `holiday_de`.
+//!
+//! The header `include/holiday_de.h` is generated by cbindgen,
+//! regenerate it with `cbindgen --config cbindgen.toml --output include/holiday_de.h` after changing this file.
+//!
+//! Regions are passed as NUL-terminated strings parsed like `GermanRegion::from_str`,
+//! e.g. `"DE-BY"`, `"NRW"` or `"Bayern"`.
+//! Holidays are identified by their index in `GermanHoliday::ALL`, which is only ever appended to.
+
+use chrono::{Datelike, NaiveDate};
+use holiday_de::{GermanHoliday, GermanRegion};
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::slice;
+
+/// Returned by all functions on invalid input, e.g. an unknown region or a null pointer.
+pub const HOLIDAY_DE_ERROR: i32 = -1;
+
+/// A public holiday on a specific date.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HolidayDeHoliday {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    /// Identifier of the holiday, pass it to `holiday_de_holiday_name` to get its name.
+    pub holiday: u32,
+}
+
+impl HolidayDeHoliday {
+    fn new(date: NaiveDate, holiday: GermanHoliday) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            holiday: holiday_id(holiday),
+        }
+    }
+}
+
+/// Returns 1 if the given date is a public holiday in the given region, 0 if it is not
+/// and `HOLIDAY_DE_ERROR` if the date or region is invalid.
+///
+/// # Safety
+///
+/// `region_code` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn holiday_de_is_holiday(
+    year: i32,
+    month: u32,
+    day: u32,
+    region_code: *const c_char,
+) -> i32 {
+    match (
+        NaiveDate::from_ymd_opt(year, month, day),
+        parse_region(region_code),
+    ) {
+        (Some(date), Some(region)) => i32::from(region.is_holiday(date)),
+        _ => HOLIDAY_DE_ERROR,
+    }
+}
+
+/// Writes the public holidays of the given region in the given year, sorted by date,
+/// into the buffer `out` with room for `capacity` entries.
+///
+/// Returns the total number of holidays, which may exceed `capacity`
+/// in which case only the first `capacity` holidays are written,
+/// or `HOLIDAY_DE_ERROR` if the region is invalid.
+/// Passing a null buffer with a capacity of 0 can be used to query the required capacity.
+///
+/// # Safety
+///
+/// `region_code` must be null or point to a NUL-terminated string,
+/// `out` must be valid for writing `capacity` entries.
+#[no_mangle]
+pub unsafe extern "C" fn holiday_de_holidays_in_year(
+    region_code: *const c_char,
+    year: i32,
+    out: *mut HolidayDeHoliday,
+    capacity: usize,
+) -> i32 {
+    let region = match parse_region(region_code) {
+        Some(region) => region,
+        None => return HOLIDAY_DE_ERROR,
+    };
+    let (holidays, len) = region.holiday_dates_in_year_array(year);
+    if !out.is_null() {
+        let out = slice::from_raw_parts_mut(out, capacity);
+        for (slot, &(date, holiday)) in out.iter_mut().zip(holidays.iter().flatten()) {
+            *slot = HolidayDeHoliday::new(date, holiday);
+        }
+    }
+    i32::try_from(len).unwrap_or(HOLIDAY_DE_ERROR)
+}
+
+/// Writes the German name of a holiday as a NUL-terminated UTF-8 string
+/// into the buffer `out` of size `len`.
+///
+/// Returns the length of the name in bytes without the terminating NUL,
+/// or `HOLIDAY_DE_ERROR` if the holiday is unknown.
+/// If the buffer is too small, the name is truncated at a character boundary,
+/// so the buffer always contains valid UTF-8.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn holiday_de_holiday_name(
+    holiday: u32,
+    out: *mut c_char,
+    len: usize,
+) -> i32 {
+    let name = match GermanHoliday::ALL.get(holiday as usize) {
+        Some(holiday) => holiday.description(),
+        None => return HOLIDAY_DE_ERROR,
+    };
+    if !out.is_null() && len > 0 {
+        let out = slice::from_raw_parts_mut(out as *mut u8, len);
+        let mut written = name.len().min(len - 1);
+        while !name.is_char_boundary(written) {
+            written -= 1;
+        }
+        out[..written].copy_from_slice(&name.as_bytes()[..written]);
+        out[written] = 0;
+    }
+    name.len() as i32
+}
+
+fn holiday_id(holiday: GermanHoliday) -> u32 {
+    GermanHoliday::ALL
+        .iter()
+        .position(|&candidate| candidate == holiday)
+        .expect("holiday missing from GermanHoliday::ALL") as u32
+}
+
+unsafe fn parse_region(region_code: *const c_char) -> Option<GermanRegion> {
+    if region_code.is_null() {
+        return None;
+    }
+    CStr::from_ptr(region_code).to_str().ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn code(nul_terminated: &'static [u8]) -> *const c_char {
+        nul_terminated.as_ptr() as *const c_char
+    }
+
+    #[test]
+    fn is_holiday() {
+        unsafe {
+            assert_eq!(holiday_de_is_holiday(2024, 10, 31, code(b"DE-SN\0")), 1);
+            assert_eq!(holiday_de_is_holiday(2024, 10, 31, code(b"Bayern\0")), 0);
+            assert_eq!(
+                holiday_de_is_holiday(2024, 2, 30, code(b"BY\0")),
+                HOLIDAY_DE_ERROR
+            );
+            assert_eq!(
+                holiday_de_is_holiday(2024, 1, 1, code(b"Bavaria\0")),
+                HOLIDAY_DE_ERROR
+            );
+            assert_eq!(
+                holiday_de_is_holiday(2024, 1, 1, std::ptr::null()),
+                HOLIDAY_DE_ERROR
+            );
+        }
+    }
+
+    #[test]
+    fn holidays_in_year() {
+        unsafe {
+            let count = holiday_de_holidays_in_year(code(b"BE\0"), 2025, std::ptr::null_mut(), 0);
+            assert_eq!(count, 11);
+            let mut buffer = [HolidayDeHoliday {
+                year: 0,
+                month: 0,
+                day: 0,
+                holiday: 0,
+            }; 3];
+            assert_eq!(
+                holiday_de_holidays_in_year(code(b"BE\0"), 2025, buffer.as_mut_ptr(), 3),
+                11
+            );
+            assert_eq!(
+                buffer[1],
+                HolidayDeHoliday::new(
+                    NaiveDate::from_ymd_opt(2025, 3, 8).unwrap(),
+                    GermanHoliday::Frauentag
+                )
+            );
+            let mut name = [0 as c_char; 8];
+            assert_eq!(
+                holiday_de_holiday_name(buffer[1].holiday, name.as_mut_ptr(), name.len()),
+                9
+            );
+            assert_eq!(CStr::from_ptr(name.as_ptr()).to_str(), Ok("Frauent"));
+        }
+    }
+
+    #[test]
+    fn holiday_name_is_truncated_at_char_boundary() {
+        let holiday = holiday_id(GermanHoliday::MariaeHimmelfahrt);
+        let mut name = [0 as c_char; 6];
+        unsafe {
+            assert_eq!(
+                holiday_de_holiday_name(holiday, name.as_mut_ptr(), name.len()),
+                18
+            );
+            assert_eq!(CStr::from_ptr(name.as_ptr()).to_str(), Ok("Mari"));
+        }
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+        let mut header = Vec::new();
+        cbindgen::generate_with_config(crate_dir, config)
+            .unwrap()
+            .write(&mut header);
+        let path = crate_dir.join("include/holiday_de.h");
+        if std::env::var_os("UPDATE_HEADER").is_some() {
+            std::fs::write(&path, &header).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            std::fs::read_to_string(path).unwrap(),
+            "header out of date, rerun this test with UPDATE_HEADER=1"
+        );
+    }
+}
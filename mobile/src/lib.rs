@@ -0,0 +1,180 @@
+//! Swift and Kotlin bindings for `holiday_de`, built with UniFFI.
+//!
+//! Build the library, then generate the bindings with
+//! `cargo run --features bindgen --bin uniffi-bindgen generate --library <path to the library> --language swift`
+//! (or `--language kotlin`).
+use chrono::{Datelike, NaiveDate};
+use holiday_de::{GermanHoliday, GermanRegion};
+use std::convert::{TryFrom, TryInto};
+
+uniffi::setup_scaffolding!();
+
+#[uniffi::remote(Enum)]
+pub enum GermanRegion {
+    BadenWuerttemberg,
+    Bayern,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hessen,
+    MechlenburgVorpommern,
+    Niedersachsen,
+    NordrheinWestfalen,
+    RheinlandPfalz,
+    Saarland,
+    Sachsen,
+    SachsenAnhalt,
+    SchleswigHolstein,
+    Thueringen,
+    Bundesweit,
+}
+
+#[uniffi::remote(Enum)]
+pub enum GermanHoliday {
+    Neujahr,
+    HeiligeDreiKoenige,
+    Frauentag,
+    Faschingsdienstag,
+    Aschermittwoch,
+    Gruendonnerstag,
+    Karfreitag,
+    Ostersonntag,
+    Ostermontag,
+    ErsterMai,
+    TagDerBefreiung,
+    TagDesSieges,
+    ChristiHimmelfahrt,
+    Pfingstsonntag,
+    Pfingstmontag,
+    Fronleichnam,
+    SiebzehnterJuni,
+    AugsburgerFriedensfest,
+    MariaeHimmelfahrt,
+    Weltkindertag,
+    TagDerRepublik,
+    TagDerDeutschenEinheit,
+    Reformationstag,
+    Allerheiligen,
+    BussUndBettag,
+    Heiligabend,
+    ErsterWeihnachtsfeiertag,
+    ZweiterWeihnachtsfeiertag,
+    Silvester,
+    Weiberfastnacht,
+    Rosenmontag,
+}
+
+/// A calendar date, named to avoid clashing with `Foundation.Date` in Swift.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, uniffi::Record)]
+pub struct CivilDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl From<NaiveDate> for CivilDate {
+    fn from(date: NaiveDate) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+impl TryFrom<CivilDate> for NaiveDate {
+    type Error = HolidayException;
+
+    fn try_from(date: CivilDate) -> Result<Self, HolidayException> {
+        NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+            .ok_or(HolidayException::InvalidDate)
+    }
+}
+
+/// A public holiday on a specific date.
+#[derive(Clone, Debug, Eq, PartialEq, uniffi::Record)]
+pub struct Holiday {
+    pub date: CivilDate,
+    pub holiday: GermanHoliday,
+    /// German name of the holiday, e.g. `Tag der Deutschen Einheit`.
+    pub name: String,
+}
+
+impl Holiday {
+    fn new(date: NaiveDate, holiday: GermanHoliday) -> Self {
+        Self {
+            date: date.into(),
+            holiday,
+            name: holiday.description().to_owned(),
+        }
+    }
+}
+
+/// Errors thrown by the bindings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error, uniffi::Error)]
+pub enum HolidayException {
+    #[error("invalid date")]
+    InvalidDate,
+}
+
+/// Returns true if the given date is a public holiday in the given region.
+#[uniffi::export]
+pub fn is_holiday(date: CivilDate, region: GermanRegion) -> Result<bool, HolidayException> {
+    Ok(region.is_holiday(date.try_into()?))
+}
+
+/// Returns all public holidays of the given region in the given year, sorted by date.
+#[uniffi::export]
+pub fn holidays_in_year(region: GermanRegion, year: i32) -> Vec<Holiday> {
+    region
+        .iter_holiday_dates(year)
+        .map(|(date, holiday)| Holiday::new(date, holiday))
+        .collect()
+}
+
+/// Returns the first public holiday of the given region on or after the given date.
+#[uniffi::export]
+pub fn next_holiday(
+    region: GermanRegion,
+    from: CivilDate,
+) -> Result<Option<Holiday>, HolidayException> {
+    Ok(region
+        .iter_holidays_from(from.try_into()?)
+        .next()
+        .map(|(date, holiday)| Holiday::new(date, holiday)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exported_functions() {
+        let date = |year, month, day| CivilDate { year, month, day };
+        assert_eq!(
+            is_holiday(date(2024, 10, 31), GermanRegion::Sachsen),
+            Ok(true)
+        );
+        assert_eq!(
+            is_holiday(date(2024, 10, 31), GermanRegion::Bayern),
+            Ok(false)
+        );
+        assert_eq!(
+            is_holiday(date(2024, 2, 30), GermanRegion::Bayern),
+            Err(HolidayException::InvalidDate)
+        );
+        assert_eq!(
+            holidays_in_year(GermanRegion::NordrheinWestfalen, 2024).len(),
+            11
+        );
+        assert_eq!(
+            next_holiday(GermanRegion::Berlin, date(2025, 3, 1)),
+            Ok(Some(Holiday {
+                date: date(2025, 3, 8),
+                holiday: GermanHoliday::Frauentag,
+                name: "Frauentag".to_owned(),
+            }))
+        );
+    }
+}
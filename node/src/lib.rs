@@ -0,0 +1,145 @@
+//! Node.js bindings for `holiday_de`, built with napi-rs.
+//!
+//! Dates are passed as ISO 8601 strings (`YYYY-MM-DD`), regions as strings parsed like
+//! `GermanRegion::from_str`, e.g. `DE-BY`, `NRW` or `Thüringen`.
+use chrono::NaiveDate;
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use holiday_de::{GermanHoliday, GermanRegion};
+
+/// A public holiday on a specific date.
+#[napi(object)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Holiday {
+    /// ISO 8601 date, e.g. `2024-10-03`.
+    pub date: String,
+    /// Identifier of the holiday, e.g. `TagDerDeutschenEinheit`.
+    pub holiday: String,
+    /// German name of the holiday, e.g. `Tag der Deutschen Einheit`.
+    pub name: String,
+}
+
+impl Holiday {
+    fn new(date: NaiveDate, holiday: GermanHoliday) -> Self {
+        Self {
+            date: date.to_string(),
+            holiday: format!("{:?}", holiday),
+            name: holiday.description().to_owned(),
+        }
+    }
+}
+
+/// Returns the ISO 3166-2 codes of all regions, e.g. `DE-BY`.
+#[napi]
+pub fn regions() -> Vec<String> {
+    GermanRegion::ALL
+        .iter()
+        .map(|region| region.iso_code().to_owned())
+        .collect()
+}
+
+/// Returns true if the given date (`YYYY-MM-DD`) is a public holiday in the given region.
+#[napi]
+pub fn is_holiday(date: String, region: String) -> Result<bool> {
+    Ok(holiday_on(date, region)?.is_some())
+}
+
+/// Returns the public holiday on the given date (`YYYY-MM-DD`) in the given region, if any.
+#[napi]
+pub fn holiday_on(date: String, region: String) -> Result<Option<Holiday>> {
+    let date = parse_date(&date)?;
+    Ok(parse_region(&region)?
+        .holiday_from_date(date)
+        .map(|holiday| Holiday::new(date, holiday)))
+}
+
+/// Returns all public holidays of the given region in the given year, sorted by date.
+#[napi]
+pub fn holidays_in_year(region: String, year: i32) -> Result<Vec<Holiday>> {
+    Ok(parse_region(&region)?
+        .iter_holiday_dates(year)
+        .map(|(date, holiday)| Holiday::new(date, holiday))
+        .collect())
+}
+
+/// Returns the first public holiday of the given region on or after the given date (`YYYY-MM-DD`).
+#[napi]
+pub fn next_holiday(region: String, from: String) -> Result<Option<Holiday>> {
+    Ok(parse_region(&region)?
+        .iter_holidays_from(parse_date(&from)?)
+        .next()
+        .map(|(date, holiday)| Holiday::new(date, holiday)))
+}
+
+/// Returns true if the given date (`YYYY-MM-DD`) is neither a weekend nor a public holiday in the given region.
+#[napi]
+pub fn is_working_day(date: String, region: String) -> Result<bool> {
+    let date = parse_date(&date)?;
+    Ok(parse_region(&region)?
+        .free_days_between(date, date)
+        .days
+        .is_empty())
+}
+
+/// Returns the number of working days from `from` to `to` (`YYYY-MM-DD`), both inclusive.
+#[napi]
+pub fn working_days_between(region: String, from: String, to: String) -> Result<u32> {
+    let region = parse_region(&region)?;
+    let (from, to) = (parse_date(&from)?, parse_date(&to)?);
+    if to < from {
+        return Ok(0);
+    }
+    let days = (to - from).num_days() + 1;
+    Ok((days - region.free_days_between(from, to).days.len() as i64) as u32)
+}
+
+fn parse_region(input: &str) -> Result<GermanRegion> {
+    input
+        .parse()
+        .map_err(|_| Error::from_reason(format!("unknown region: {}", input)))
+}
+
+fn parse_date(input: &str) -> Result<NaiveDate> {
+    input
+        .parse()
+        .map_err(|_| Error::from_reason(format!("invalid date, expected YYYY-MM-DD: {}", input)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exported_functions() {
+        let s = str::to_owned;
+        assert_eq!(regions().len(), 16);
+        assert!(is_holiday(s("2024-10-31"), s("DE-SN")).unwrap());
+        assert!(!is_working_day(s("2024-10-31"), s("DE-SN")).unwrap());
+        assert!(is_working_day(s("2024-10-31"), s("Bayern")).unwrap());
+        assert_eq!(
+            working_days_between(s("BY"), s("2024-12-01"), s("2024-12-31")).unwrap(),
+            20
+        );
+        assert_eq!(
+            next_holiday(s("BE"), s("2025-03-01")).unwrap(),
+            Some(Holiday {
+                date: s("2025-03-08"),
+                holiday: s("Frauentag"),
+                name: s("Frauentag"),
+            })
+        );
+        assert_eq!(holidays_in_year(s("nrw"), 2024).unwrap().len(), 11);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            is_holiday("2024-01-01".to_owned(), "Bavaria".to_owned())
+                .unwrap_err()
+                .reason,
+            "unknown region: Bavaria"
+        );
+        assert!(holiday_on("31.10.2024".to_owned(), "BY".to_owned()).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, BooleanArray, Date32Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use chrono::NaiveDate;
+
+use crate::calendar_date::CalendarDate;
+use crate::civil;
+use crate::regions::GermanRegion;
+
+impl GermanRegion {
+    /// Returns one row per day between `from` and `to` (both inclusive) as an Arrow `RecordBatch`,
+    /// ready to be joined onto data frames, e.g. with Polars.
+    ///
+    /// The columns are `date` (`Date32`), `is_holiday` (`Boolean`) and the nullable
+    /// `holiday` and `name` (`Utf8`) with the identifier and German name of the public holiday.
+    pub fn holiday_record_batch(&self, from: NaiveDate, to: NaiveDate) -> RecordBatch {
+        let days = from.iter_days().take_while(|date| *date <= to);
+        let mut dates = Vec::new();
+        let mut holidays = Vec::new();
+        for (date, holiday) in self.iter_classify_dates(days) {
+            dates.push(civil::days_from_civil(date.to_ymd()) as i32);
+            holidays.push(holiday);
+        }
+        let schema = Schema::new(vec![
+            Field::new("date", DataType::Date32, false),
+            Field::new("is_holiday", DataType::Boolean, false),
+            Field::new("holiday", DataType::Utf8, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Date32Array::from(dates)),
+            Arc::new(
+                holidays
+                    .iter()
+                    .map(|holiday| Some(holiday.is_some()))
+                    .collect::<BooleanArray>(),
+            ),
+            Arc::new(
+                holidays
+                    .iter()
+                    .map(|holiday| holiday.map(|holiday| holiday.identifier()))
+                    .collect::<StringArray>(),
+            ),
+            Arc::new(
+                holidays
+                    .iter()
+                    .map(|holiday| holiday.map(|holiday| holiday.description()))
+                    .collect::<StringArray>(),
+            ),
+        ];
+        RecordBatch::try_new(Arc::new(schema), columns).expect("columns match the schema")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Date32Type;
+    use arrow_array::Array;
+    use chrono::NaiveDate;
+
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn holiday_record_batch() {
+        let from = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        let batch = Berlin.holiday_record_batch(from, to);
+        assert_eq!(batch.num_rows(), 10);
+        let dates = batch.column(0).as_primitive::<Date32Type>();
+        assert_eq!(dates.value_as_date(0), Some(from));
+        assert_eq!(dates.value_as_date(9), Some(to));
+        let is_holiday = batch.column(1).as_boolean();
+        assert_eq!(
+            (0..10).filter(|&i| is_holiday.value(i)).collect::<Vec<_>>(),
+            [1, 2, 8]
+        );
+        let holiday = batch.column(2).as_string::<i32>();
+        assert_eq!(holiday.value(8), "Neujahr");
+        assert!(holiday.is_null(0));
+        let name = batch.column(3).as_string::<i32>();
+        assert_eq!(name.value(1), "Erster Weihnachtsfeiertag");
+        assert_eq!(Berlin.holiday_record_batch(to, from).num_rows(), 0);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::bundesland::Bundesland;
+
+/// Amtlicher Gemeindeschlüssel of the city of Augsburg, the only municipality observing the Augsburger Friedensfest.
+const AUGSBURG_AGS: &str = "09761000";
+
+/// Postal codes of the city of Augsburg, excluding those for PO boxes and large recipients.
+///
+/// Surrounding municipalities like Gersthofen, Neusäß, Stadtbergen, Friedberg or Königsbrunn
+/// have postal codes of their own, so none of these codes are shared with them.
+const AUGSBURG_PLZ: &[&str] = &[
+    "86150", "86152", "86153", "86154", "86156", "86157", "86159", "86161", "86163", "86165",
+    "86167", "86169", "86179", "86199",
+];
+
+/// True if the Amtlicher Gemeindeschlüssel (8 digits) or Amtlicher Regionalschlüssel (12 digits)
+/// refers to the city of Augsburg, where the Augsburger Friedensfest is a public holiday.
+///
+/// Only the city itself observes the Augsburger Friedensfest, not the surrounding Landkreis Augsburg
+/// (key `09772…`), whose municipalities keep the holidays of `GermanRegion::Bayern`.
+pub fn is_augsburg_ags(ags: &str) -> bool {
+    if Bundesland::from_ags(ags).is_none() {
+        return false;
+    }
+    // the ARS inserts four digits for the Gemeindeverband before the last three digits of the AGS
+    ags[..5] == AUGSBURG_AGS[..5] && ags[ags.len() - 3..] == AUGSBURG_AGS[5..]
+}
+
+/// True if the postal code belongs to an address within the city of Augsburg.
+///
+/// Prefer `is_augsburg_ags` if the Amtlicher Gemeindeschlüssel is known.
+pub fn is_augsburg_plz(plz: &str) -> bool {
+    AUGSBURG_PLZ.contains(&plz.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn augsburg_ags() {
+        assert!(is_augsburg_ags("09761000"));
+        assert!(is_augsburg_ags("097610000000"));
+        // Landkreis Augsburg, e.g. Gersthofen and Stadtbergen
+        assert!(!is_augsburg_ags("09772147"));
+        assert!(!is_augsburg_ags("09772200"));
+        assert!(!is_augsburg_ags("0976100"));
+        assert!(!is_augsburg_ags("Augsburg"));
+    }
+
+    #[test]
+    fn augsburg_plz() {
+        assert!(is_augsburg_plz("86150"));
+        assert!(is_augsburg_plz(" 86199 "));
+        // Gersthofen, Neusäß, Stadtbergen, Friedberg
+        for plz in ["86368", "86356", "86391", "86316"] {
+            assert!(!is_augsburg_plz(plz));
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use chrono::NaiveDate;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use holiday_de::{to_csv, Clock, GermanHoliday, GermanRegion, SystemClock};
+use serde::Serialize;
+use std::process::ExitCode;
+
+/// Public holidays in Germany.
+#[derive(Parser)]
+#[command(name = "holiday-de", version)]
+struct Args {
+    /// Print machine-readable JSON instead of text.
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List all public holidays of a region in a year.
+    List {
+        #[arg(value_parser = parse_region)]
+        region: GermanRegion,
+        year: i32,
+    },
+    /// Check whether a date is a public holiday, exiting with status 1 if it is not.
+    Check {
+        #[arg(value_parser = parse_date)]
+        date: NaiveDate,
+        #[arg(value_parser = parse_region)]
+        region: GermanRegion,
+    },
+    /// Show the next public holiday of a region strictly after today.
+    Next {
+        #[arg(value_parser = parse_region)]
+        region: GermanRegion,
+        /// Date to search after instead of today.
+        #[arg(long, value_parser = parse_date)]
+        from: Option<NaiveDate>,
+    },
+    /// Export the public holidays of a region.
+    Export {
+        #[arg(value_parser = parse_region)]
+        region: GermanRegion,
+        year: i32,
+        /// Last year to export, defaults to `year`.
+        #[arg(long)]
+        to: Option<i32>,
+        /// Output format, defaults to `json` if `--json` is given.
+        #[arg(long, value_enum, required_unless_present = "json")]
+        format: Option<Format>,
+    },
+    /// Print a shell completion script.
+    Completions { shell: Shell },
+}
+
+impl Args {
+    /// Returns an error message if `--json` is given for a command which cannot print JSON.
+    fn check_json(&self) -> Result<(), &'static str> {
+        if !self.json {
+            return Ok(());
+        }
+        match self.command {
+            Command::Export {
+                format: Some(Format::Ics | Format::Csv),
+                ..
+            } => Err("--json cannot be used with --format ics or --format csv"),
+            Command::Completions { .. } => Err("--json cannot be used with completions"),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Ics,
+    /// The same objects as printed by the other commands with `--json`.
+    Json,
+    Csv,
+    /// JSON in the format of the OpenHolidays API.
+    #[value(name = "openholidays")]
+    OpenHolidays,
+}
+
+/// A holiday as printed with `--json`.
+#[derive(Serialize)]
+struct HolidayOutput {
+    /// ISO 8601 date.
+    date: String,
+    region: &'static str,
+    /// Stable identifier, see `GermanHoliday::slug`.
+    holiday: &'static str,
+    name: &'static str,
+}
+
+impl HolidayOutput {
+    fn new(date: NaiveDate, region: GermanRegion, holiday: GermanHoliday) -> Self {
+        Self {
+            date: date.to_string(),
+            region: region.iso_code(),
+            holiday: holiday.slug(),
+            name: holiday.description(),
+        }
+    }
+}
+
+fn print_json(value: &impl Serialize) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).expect("failed to serialize")
+    );
+}
+
+fn parse_region(input: &str) -> Result<GermanRegion, String> {
+    input.parse().map_err(|_| {
+        format!(
+            "unknown region {:?}, expected e.g. DE-BY, NRW or Thüringen",
+            input
+        )
+    })
+}
+
+/// Parses a date given as `24.12.2024` or `2024-12-24`.
+fn parse_date(input: &str) -> Result<NaiveDate, String> {
+    let german = input.contains('.');
+    let mut parts = input
+        .splitn(3, if german { '.' } else { '-' })
+        .collect::<Vec<_>>();
+    if german {
+        parts.reverse();
+    }
+    match *parts {
+        [year, month, day] => year
+            .parse()
+            .ok()
+            .zip(month.parse().ok())
+            .zip(day.parse().ok())
+            .and_then(|((year, month), day)| NaiveDate::from_ymd_opt(year, month, day)),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        format!(
+            "invalid date {:?}, expected DD.MM.YYYY or YYYY-MM-DD",
+            input
+        )
+    })
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Err(message) = args.check_json() {
+        Args::command()
+            .error(ErrorKind::ArgumentConflict, message)
+            .exit();
+    }
+    let Args { json, command } = args;
+    match command {
+        Command::List { region, year } => {
+            let holidays = region.holiday_dates_in_year(year);
+            if json {
+                print_json(
+                    &holidays
+                        .into_iter()
+                        .map(|(date, holiday)| HolidayOutput::new(date, region, holiday))
+                        .collect::<Vec<_>>(),
+                );
+            } else {
+                for (date, holiday) in holidays {
+                    println!("{} {}", date, holiday.description());
+                }
+            }
+        }
+        Command::Check { date, region } => {
+            let holiday = region.holiday_from_date(date);
+            if json {
+                print_json(&holiday.map(|holiday| HolidayOutput::new(date, region, holiday)));
+            } else if let Some(holiday) = holiday {
+                println!("{}", holiday.description());
+            }
+            if holiday.is_none() {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Next { region, from } => {
+            let from = from.unwrap_or_else(|| SystemClock.today());
+            let next = region.next_holiday(from);
+            if json {
+                print_json(&next.map(|(date, holiday)| HolidayOutput::new(date, region, holiday)));
+            } else if let Some((date, holiday)) = next {
+                println!("{} {}", date, holiday.description());
+            }
+            if next.is_none() {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Export {
+            region,
+            year,
+            to,
+            format,
+        } => {
+            let years = year..=to.unwrap_or(year);
+            match format.unwrap_or(Format::Json) {
+                Format::Ics => print!("{}", region.to_ics(years)),
+                Format::Csv => print!("{}", to_csv(&[region], years)),
+                Format::Json => print_json(
+                    &years
+                        .flat_map(|year| region.iter_holiday_dates(year))
+                        .map(|(date, holiday)| HolidayOutput::new(date, region, holiday))
+                        .collect::<Vec<_>>(),
+                ),
+                Format::OpenHolidays => {
+                    let holidays = years
+                        .flat_map(|year| {
+                            serde_json::from_str::<Vec<serde_json::Value>>(
+                                &region.open_holidays_json(year),
+                            )
+                            .expect("generated invalid JSON")
+                        })
+                        .collect::<Vec<_>>();
+                    print_json(&holidays);
+                }
+            }
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Args::command(),
+                "holiday-de",
+                &mut std::io::stdout(),
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_region() {
+        assert_eq!(super::parse_region("Bayern"), Ok(GermanRegion::Bayern));
+        assert_eq!(super::parse_region("de-by"), Ok(GermanRegion::Bayern));
+        assert_eq!(
+            super::parse_region("NRW"),
+            Ok(GermanRegion::NordrheinWestfalen)
+        );
+        assert!(super::parse_region("Bavaria").is_err());
+    }
+
+    #[test]
+    fn parse_date() {
+        assert_eq!(
+            super::parse_date("2024-12-24"),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+        );
+        assert_eq!(
+            super::parse_date("24.12.2024"),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+        );
+        assert!(super::parse_date("2024-02-30").is_err());
+        assert!(super::parse_date("24.12.").is_err());
+    }
+
+    #[test]
+    fn holiday_output() {
+        let output = HolidayOutput::new(
+            NaiveDate::from_ymd_opt(2024, 10, 3).unwrap(),
+            GermanRegion::Berlin,
+            GermanHoliday::TagDerDeutschenEinheit,
+        );
+        assert_eq!(
+            serde_json::to_string(&output).unwrap(),
+            r#"{"date":"2024-10-03","region":"DE-BE","holiday":"tag-der-deutschen-einheit","name":"Tag der Deutschen Einheit"}"#
+        );
+    }
+
+    #[test]
+    fn export_format_defaults_to_json() {
+        assert!(Args::try_parse_from(["holiday-de", "export", "BY", "2024"]).is_err());
+        let args = Args::try_parse_from(["holiday-de", "export", "BY", "2024", "--json"]).unwrap();
+        assert!(args.json);
+        assert!(args.check_json().is_ok());
+        let args = Args::try_parse_from([
+            "holiday-de",
+            "export",
+            "BY",
+            "2024",
+            "--format",
+            "openholidays",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Export {
+                format: Some(Format::OpenHolidays),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn json_is_rejected_where_unsupported() {
+        for args in [
+            &[
+                "holiday-de",
+                "export",
+                "BY",
+                "2024",
+                "--format",
+                "ics",
+                "--json",
+            ][..],
+            &[
+                "holiday-de",
+                "export",
+                "BY",
+                "2024",
+                "--format",
+                "csv",
+                "--json",
+            ],
+            &["holiday-de", "completions", "bash", "--json"],
+        ] {
+            assert!(Args::try_parse_from(args).unwrap().check_json().is_err());
+        }
+        let args = Args::try_parse_from(["holiday-de", "list", "BY", "2024", "--json"]).unwrap();
+        assert!(args.check_json().is_ok());
+    }
+
+    #[test]
+    fn verify_args() {
+        Args::command().debug_assert();
+    }
+}
@@ -0,0 +1,396 @@
+use core::convert::TryFrom;
+
+use crate::augsburg::is_augsburg_ags;
+use crate::error::NotABundeslandError;
+use crate::holiday_set::HolidaySet;
+use crate::holidays::GermanHoliday::{self, *};
+use crate::regions::{GermanRegion, FIRST_YEAR};
+
+/// The 16 federal states of Germany, for integrations which only know the state of a location.
+///
+/// Each state currently corresponds to exactly one `GermanRegion`, which applies the holidays
+/// of the majority of its communities (see the documentation of the respective region).
+/// Use `Bundesland::regions` instead of assuming this, since states may be split into
+/// several regions in the future.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Bundesland {
+    BadenWuerttemberg,
+    Bayern,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hessen,
+    MecklenburgVorpommern,
+    Niedersachsen,
+    NordrheinWestfalen,
+    RheinlandPfalz,
+    Saarland,
+    Sachsen,
+    SachsenAnhalt,
+    SchleswigHolstein,
+    Thueringen,
+}
+
+use Bundesland::*;
+
+/// Holidays which only apply in some communities of a state, see the documentation of `GermanRegion`.
+///
+/// The first list contains holidays excluded from the state's regions, the second one
+/// holidays which are included although they don't apply in all communities.
+const PARTIAL_HOLIDAYS: &[(Bundesland, &[GermanHoliday], &[GermanHoliday])] = &[
+    (Bayern, &[AugsburgerFriedensfest], &[MariaeHimmelfahrt]),
+    (Sachsen, &[Fronleichnam], &[]),
+    (Thueringen, &[Fronleichnam], &[]),
+];
+
+impl Bundesland {
+    /// All federal states in alphabetical order.
+    pub const ALL: &'static [Bundesland] = &[
+        BadenWuerttemberg,
+        Bayern,
+        Berlin,
+        Brandenburg,
+        Bremen,
+        Hamburg,
+        Hessen,
+        MecklenburgVorpommern,
+        Niedersachsen,
+        NordrheinWestfalen,
+        RheinlandPfalz,
+        Saarland,
+        Sachsen,
+        SachsenAnhalt,
+        SchleswigHolstein,
+        Thueringen,
+    ];
+
+    /// The region used when only the state is known, also used by `From<Bundesland> for GermanRegion`.
+    pub const fn default_region(&self) -> GermanRegion {
+        match self {
+            BadenWuerttemberg => GermanRegion::BadenWuerttemberg,
+            Bayern => GermanRegion::Bayern,
+            Berlin => GermanRegion::Berlin,
+            Brandenburg => GermanRegion::Brandenburg,
+            Bremen => GermanRegion::Bremen,
+            Hamburg => GermanRegion::Hamburg,
+            Hessen => GermanRegion::Hessen,
+            MecklenburgVorpommern => GermanRegion::MechlenburgVorpommern,
+            Niedersachsen => GermanRegion::Niedersachsen,
+            NordrheinWestfalen => GermanRegion::NordrheinWestfalen,
+            RheinlandPfalz => GermanRegion::RheinlandPfalz,
+            Saarland => GermanRegion::Saarland,
+            Sachsen => GermanRegion::Sachsen,
+            SachsenAnhalt => GermanRegion::SachsenAnhalt,
+            SchleswigHolstein => GermanRegion::SchleswigHolstein,
+            Thueringen => GermanRegion::Thueringen,
+        }
+    }
+
+    /// Returns the German name of the state, e.g. `"Baden-Württemberg"`, see `GermanRegion::name`.
+    pub const fn name(&self) -> &'static str {
+        self.default_region().name()
+    }
+
+    /// Returns the English name of the state, e.g. `"Bavaria"`, see `GermanRegion::english_name`.
+    pub const fn english_name(&self) -> &'static str {
+        self.default_region().english_name()
+    }
+
+    /// Returns the official German name of the state, e.g. `"Freistaat Bayern"`,
+    /// see `GermanRegion::official_name`.
+    pub const fn official_name(&self) -> &'static str {
+        self.default_region().official_name()
+    }
+
+    /// Returns a lowercase ASCII identifier of the state for use in URLs, e.g. `"baden-wuerttemberg"`,
+    /// see `GermanRegion::slug`.
+    pub const fn slug(&self) -> &'static str {
+        self.default_region().slug()
+    }
+
+    /// Returns the ISO 3166-2 code of the state, e.g. `"DE-BY"` for Bayern.
+    pub const fn iso_3166_2(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "DE-BW",
+            Bayern => "DE-BY",
+            Berlin => "DE-BE",
+            Brandenburg => "DE-BB",
+            Bremen => "DE-HB",
+            Hamburg => "DE-HH",
+            Hessen => "DE-HE",
+            MecklenburgVorpommern => "DE-MV",
+            Niedersachsen => "DE-NI",
+            NordrheinWestfalen => "DE-NW",
+            RheinlandPfalz => "DE-RP",
+            Saarland => "DE-SL",
+            Sachsen => "DE-SN",
+            SachsenAnhalt => "DE-ST",
+            SchleswigHolstein => "DE-SH",
+            Thueringen => "DE-TH",
+        }
+    }
+
+    /// Returns the state of an Amtlicher Gemeindeschlüssel (AGS, 8 digits, e.g. `"09761000"` for Augsburg)
+    /// or Amtlicher Regionalschlüssel (ARS, 12 digits), given by its first two digits.
+    ///
+    /// `None` if the key has the wrong length, contains anything but digits or has an unknown state code.
+    pub fn from_ags(ags: &str) -> Option<Bundesland> {
+        if !matches!(ags.len(), 8 | 12) || !ags.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let code = ags[..2].parse::<usize>().ok()?;
+        // state codes are assigned from north to south, Berlin and the new states following in 1990
+        [
+            SchleswigHolstein,
+            Hamburg,
+            Niedersachsen,
+            Bremen,
+            NordrheinWestfalen,
+            Hessen,
+            RheinlandPfalz,
+            BadenWuerttemberg,
+            Bayern,
+            Saarland,
+            Berlin,
+            Brandenburg,
+            MecklenburgVorpommern,
+            Sachsen,
+            SachsenAnhalt,
+            Thueringen,
+        ]
+        .get(code.checked_sub(1)?)
+        .copied()
+    }
+
+    /// All regions within this state, starting with `default_region`.
+    pub fn regions(&self) -> &'static [GermanRegion] {
+        let index = Bundesland::ALL
+            .iter()
+            .position(|state| state == self)
+            .expect("all states are listed");
+        // `GermanRegion::ALL` lists the region of each state in the same order
+        &GermanRegion::ALL[index..=index]
+    }
+
+    /// Returns the holidays in the given year which are public holidays in at least one community
+    /// of this state, e.g. including the Augsburger Friedensfest for Bayern.
+    ///
+    /// This is the conservative choice for statewide employers.
+    pub fn holidays_anywhere_in_state(&self, year: i32) -> HolidaySet {
+        let mut holidays = self
+            .regions()
+            .iter()
+            .fold(HolidaySet::EMPTY, |holidays, region| {
+                holidays | region.holiday_set(year)
+            });
+        if year >= FIRST_YEAR {
+            holidays.extend(self.partial_holidays().0.iter().copied());
+        }
+        holidays
+    }
+
+    /// Returns the holidays in the given year which are public holidays in all communities
+    /// of this state, e.g. excluding Mariä Himmelfahrt for Bayern.
+    pub fn holidays_throughout_state(&self, year: i32) -> HolidaySet {
+        // each state has at least one region
+        let holidays = self
+            .regions()
+            .iter()
+            .fold(HolidaySet::all(), |holidays, region| {
+                holidays & region.holiday_set(year)
+            });
+        holidays - self.partial_holidays().1.iter().copied().collect()
+    }
+
+    fn partial_holidays(&self) -> (&'static [GermanHoliday], &'static [GermanHoliday]) {
+        PARTIAL_HOLIDAYS
+            .iter()
+            .find(|(state, _, _)| state == self)
+            .map_or((&[], &[]), |&(_, excluded, included)| (excluded, included))
+    }
+}
+
+/// Returns the public holidays in the given year in the municipality with the given
+/// Amtlicher Gemeindeschlüssel (or Amtlicher Regionalschlüssel), see `Bundesland::from_ags`.
+///
+/// In addition to the holidays of the state's `default_region`, this includes
+/// the Augsburger Friedensfest in Augsburg. The remaining municipal differences,
+/// Mariä Himmelfahrt in protestant communities of Bayern and Fronleichnam in catholic communities
+/// of Sachsen and Thüringen, are not resolved, since this crate has no list of these communities.
+pub fn municipal_holidays(ags: &str, year: i32) -> Option<HolidaySet> {
+    let mut holidays = Bundesland::from_ags(ags)?
+        .default_region()
+        .holiday_set(year);
+    if year >= FIRST_YEAR && is_augsburg_ags(ags) {
+        holidays.insert(AugsburgerFriedensfest);
+    }
+    Some(holidays)
+}
+
+impl GermanRegion {
+    /// Returns the region of an Amtlicher Gemeindeschlüssel or Amtlicher Regionalschlüssel,
+    /// i.e. the `default_region` of `Bundesland::from_ags`.
+    ///
+    /// Use `municipal_holidays` to also take holidays of single municipalities into account.
+    pub fn from_ags(ags: &str) -> Option<GermanRegion> {
+        Bundesland::from_ags(ags).map(|state| state.default_region())
+    }
+
+    /// The federal state of this region, `None` for `Bundesweit`.
+    pub const fn bundesland(&self) -> Option<Bundesland> {
+        Some(match self {
+            GermanRegion::BadenWuerttemberg => BadenWuerttemberg,
+            GermanRegion::Bayern => Bayern,
+            GermanRegion::Berlin => Berlin,
+            GermanRegion::Brandenburg => Brandenburg,
+            GermanRegion::Bremen => Bremen,
+            GermanRegion::Hamburg => Hamburg,
+            GermanRegion::Hessen => Hessen,
+            GermanRegion::MechlenburgVorpommern => MecklenburgVorpommern,
+            GermanRegion::Niedersachsen => Niedersachsen,
+            GermanRegion::NordrheinWestfalen => NordrheinWestfalen,
+            GermanRegion::RheinlandPfalz => RheinlandPfalz,
+            GermanRegion::Saarland => Saarland,
+            GermanRegion::Sachsen => Sachsen,
+            GermanRegion::SachsenAnhalt => SachsenAnhalt,
+            GermanRegion::SchleswigHolstein => SchleswigHolstein,
+            GermanRegion::Thueringen => Thueringen,
+            GermanRegion::Bundesweit => return None,
+        })
+    }
+}
+
+impl From<Bundesland> for GermanRegion {
+    fn from(state: Bundesland) -> Self {
+        state.default_region()
+    }
+}
+
+impl TryFrom<GermanRegion> for Bundesland {
+    type Error = NotABundeslandError;
+
+    fn try_from(region: GermanRegion) -> Result<Self, NotABundeslandError> {
+        region.bundesland().ok_or(NotABundeslandError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use super::*;
+
+    #[test]
+    fn conversions_roundtrip() {
+        assert_eq!(Bundesland::ALL.len(), GermanRegion::ALL.len());
+        for &state in Bundesland::ALL {
+            assert_eq!(state.regions()[0], state.default_region());
+            for &region in state.regions() {
+                assert_eq!(Bundesland::try_from(region), Ok(state));
+            }
+            assert_eq!(GermanRegion::from(state).bundesland(), Some(state));
+        }
+        assert_eq!(
+            Bundesland::try_from(GermanRegion::Bundesweit),
+            Err(NotABundeslandError)
+        );
+        assert_eq!(
+            GermanRegion::from(MecklenburgVorpommern),
+            GermanRegion::MechlenburgVorpommern
+        );
+    }
+
+    #[test]
+    fn holidays_in_parts_of_state() {
+        let bayern = GermanRegion::Bayern.holiday_set(2024);
+        assert_eq!(
+            Bayern.holidays_anywhere_in_state(2024),
+            bayern | AugsburgerFriedensfest.into()
+        );
+        assert_eq!(
+            Bayern.holidays_throughout_state(2024),
+            bayern - MariaeHimmelfahrt.into()
+        );
+        assert!(Sachsen
+            .holidays_anywhere_in_state(2024)
+            .contains(Fronleichnam));
+        assert!(!Sachsen
+            .holidays_throughout_state(2024)
+            .contains(Fronleichnam));
+        assert_eq!(
+            Berlin.holidays_anywhere_in_state(2024),
+            Berlin.holidays_throughout_state(2024)
+        );
+        assert!(Bayern.holidays_anywhere_in_state(1989).is_empty());
+    }
+
+    #[test]
+    fn iso_3166_2() {
+        for &region in GermanRegion::ALL {
+            assert_eq!(region.iso_3166_2(), Some(region.iso_code()));
+            assert_eq!(
+                GermanRegion::from_iso_3166_2(region.iso_code()),
+                Some(region)
+            );
+        }
+        assert_eq!(GermanRegion::Bundesweit.iso_3166_2(), None);
+        assert_eq!(
+            GermanRegion::from_iso_3166_2("de-by"),
+            Some(GermanRegion::Bayern)
+        );
+        assert_eq!(GermanRegion::from_iso_3166_2("BY"), None);
+        assert_eq!(GermanRegion::from_iso_3166_2("DE"), None);
+    }
+
+    #[test]
+    fn from_ags() {
+        assert_eq!(Bundesland::from_ags("09761000"), Some(Bayern));
+        assert_eq!(Bundesland::from_ags("097610000000"), Some(Bayern));
+        assert_eq!(Bundesland::from_ags("01001000"), Some(SchleswigHolstein));
+        assert_eq!(Bundesland::from_ags("16061001"), Some(Thueringen));
+        assert_eq!(Bundesland::from_ags("11000000"), Some(Berlin));
+        assert_eq!(Bundesland::from_ags("17000000"), None);
+        assert_eq!(Bundesland::from_ags("00000000"), None);
+        assert_eq!(Bundesland::from_ags("0976100"), None);
+        assert_eq!(Bundesland::from_ags("0976100x"), None);
+        assert_eq!(
+            GermanRegion::from_ags("05315000"),
+            Some(GermanRegion::NordrheinWestfalen)
+        );
+    }
+
+    #[test]
+    fn municipal_holidays() {
+        let augsburg = super::municipal_holidays("09761000", 2024).unwrap();
+        assert_eq!(
+            augsburg,
+            GermanRegion::Bayern.holiday_set(2024) | AugsburgerFriedensfest.into()
+        );
+        assert_eq!(
+            super::municipal_holidays("097610000000", 2024),
+            Some(augsburg)
+        );
+        assert_eq!(
+            super::municipal_holidays("09772125", 2024),
+            Some(GermanRegion::Bayern.holiday_set(2024))
+        );
+        assert_eq!(super::municipal_holidays("Augsburg", 2024), None);
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(Bayern.name(), "Bayern");
+        assert_eq!(Bayern.official_name(), "Freistaat Bayern");
+        assert_eq!(
+            MecklenburgVorpommern.official_name(),
+            "Mecklenburg-Vorpommern"
+        );
+        assert_eq!(MecklenburgVorpommern.slug(), "mecklenburg-vorpommern");
+        assert_eq!(BadenWuerttemberg.slug(), "baden-wuerttemberg");
+        assert_eq!(Niedersachsen.english_name(), "Lower Saxony");
+    }
+}
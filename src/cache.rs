@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::GermanHoliday;
+use crate::region_year::RegionYear;
+use crate::regions::GermanRegion;
+
+/// A thread-safe cache of `RegionYear`s, e.g. to be shared between the requests of a web server.
+///
+/// Holds at most `capacity` region-years, evicting the least recently used one when full.
+#[derive(Debug)]
+pub struct HolidayCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<(GermanRegion, i32), (Arc<RegionYear>, u64)>,
+    /// Incremented on every access to order the entries by their last use.
+    clock: u64,
+}
+
+impl HolidayCache {
+    /// Creates an empty cache holding at most `capacity` region-years.
+    ///
+    /// A capacity of 0 disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Returns the public holidays of the region in the given year, computing them on the first request.
+    pub fn region_year(&self, region: GermanRegion, year: i32) -> Arc<RegionYear> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.clock += 1;
+        let now = state.clock;
+        if let Some((region_year, last_used)) = state.entries.get_mut(&(region, year)) {
+            *last_used = now;
+            return Arc::clone(region_year);
+        }
+        let region_year = Arc::new(region.region_year(year));
+        if self.capacity > 0 {
+            if state.entries.len() >= self.capacity {
+                let least_recently_used = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, &(_, last_used))| last_used)
+                    .map(|(&key, _)| key);
+                if let Some(key) = least_recently_used {
+                    state.entries.remove(&key);
+                }
+            }
+            state
+                .entries
+                .insert((region, year), (Arc::clone(&region_year), now));
+        }
+        region_year
+    }
+
+    /// Returns the public holiday on the given date in the region, if any.
+    pub fn holiday_from_date<D: CalendarDate>(
+        &self,
+        region: GermanRegion,
+        date: &D,
+    ) -> Option<GermanHoliday> {
+        let (year, _, _) = date.to_ymd();
+        self.region_year(region, year).holiday_from_date(date)
+    }
+
+    /// True if the given date is a public holiday in the region.
+    pub fn is_holiday<D: CalendarDate>(&self, region: GermanRegion, date: &D) -> bool {
+        self.holiday_from_date(region, date).is_some()
+    }
+
+    /// Number of cached region-years.
+    pub fn len(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .len()
+    }
+
+    /// True if no region-years are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached region-years.
+    pub fn clear(&self) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::HolidayCache;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = HolidayCache::new(2);
+        let bayern = cache.region_year(Bayern, 2024);
+        cache.region_year(Berlin, 2024);
+        assert!(Arc::ptr_eq(&bayern, &cache.region_year(Bayern, 2024)));
+        cache.region_year(Hessen, 2024);
+        assert_eq!(cache.len(), 2);
+        assert!(Arc::ptr_eq(&bayern, &cache.region_year(Bayern, 2024)));
+        assert_eq!(cache.region_year(Berlin, 2024).region(), Berlin);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(HolidayCache::new(0)
+            .region_year(Bayern, 2024)
+            .is_holiday(1, 6));
+        assert!(HolidayCache::new(0).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn shared_between_threads() {
+        let cache = Arc::new(HolidayCache::new(16));
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 10, 31).unwrap();
+        let handles = crate::GermanRegion::ALL
+            .iter()
+            .map(|&region| {
+                let cache = Arc::clone(&cache);
+                std::thread::spawn(move || {
+                    cache.is_holiday(region, &date) == region.is_holiday(date)
+                })
+            })
+            .collect::<Vec<_>>();
+        assert!(handles.into_iter().all(|handle| handle.join().unwrap()));
+        assert_eq!(cache.len(), 16);
+    }
+}
@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::{GermanHoliday, HolidayType, HolidayYear};
+use crate::one_off::ONE_OFF_HOLIDAYS;
+use crate::regions::{GermanRegion, BUNDESWEITE_FEIERTAGE, FIRST_YEAR};
+use crate::rules::{HolidayRule, HOLIDAY_RULES};
+
+/// A set of holiday rules which can be used instead of the data built into this crate,
+/// e.g. to apply a newly legislated holiday before it is released as part of this crate.
+///
+/// `HolidayCalendar::builtin()` returns the same holidays as the methods on `GermanRegion`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HolidayCalendar {
+    /// Public holidays in all regions.
+    pub nationwide: Vec<GermanHoliday>,
+    /// Regional public holidays, one-off holidays are represented as rules valid for a single year.
+    pub rules: Vec<HolidayRule>,
+    /// Holidays which are excluded even if they would apply according to `nationwide` or `rules`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub removed: Vec<HolidayRule>,
+}
+
+impl HolidayCalendar {
+    /// Returns a calendar containing the data built into this crate.
+    pub fn builtin() -> Self {
+        Self {
+            nationwide: BUNDESWEITE_FEIERTAGE.to_vec(),
+            rules: HOLIDAY_RULES
+                .iter()
+                .copied()
+                .chain(ONE_OFF_HOLIDAYS.iter().map(|one_off| {
+                    HolidayRule::between(
+                        one_off.region,
+                        one_off.holiday,
+                        one_off.year,
+                        one_off.year,
+                    )
+                }))
+                .collect(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Adds a public holiday in the given region for all years.
+    ///
+    /// To add a holiday in all regions, add it to `nationwide` instead.
+    pub fn add_holiday(&mut self, region: GermanRegion, holiday: GermanHoliday) {
+        self.removed
+            .retain(|rule| rule.region != region || rule.holiday != holiday);
+        self.rules.push(HolidayRule::always(region, holiday));
+    }
+
+    /// Removes a public holiday in the given region for all years.
+    pub fn remove_holiday(&mut self, region: GermanRegion, holiday: GermanHoliday) {
+        self.removed.push(HolidayRule::always(region, holiday));
+    }
+
+    /// Reads a calendar from a JSON document in the format produced by serializing a `HolidayCalendar`.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns all public holidays in the given region and year, see `GermanRegion::holidays_in_year`.
+    pub fn holidays_in_year(&self, region: GermanRegion, year: i32) -> Vec<GermanHoliday> {
+        if year < FIRST_YEAR {
+            return Vec::new();
+        }
+        let mut holidays = self.nationwide.clone();
+        for rule in &self.rules {
+            if rule.region == region && rule.applies_in(year) && !holidays.contains(&rule.holiday) {
+                holidays.push(rule.holiday);
+            }
+        }
+        holidays.retain(|&holiday| {
+            !self.removed.iter().any(|rule| {
+                rule.region == region && rule.holiday == holiday && rule.applies_in(year)
+            })
+        });
+        holidays
+    }
+
+    /// Classifies a holiday in the given region and year, see `GermanRegion::holiday_type`.
+    pub fn holiday_type(
+        &self,
+        region: GermanRegion,
+        holiday: GermanHoliday,
+        year: i32,
+    ) -> HolidayType {
+        if self.holidays_in_year(region, year).contains(&holiday) {
+            HolidayType::Public
+        } else {
+            HolidayType::Observance
+        }
+    }
+
+    /// Returns all public holidays and their dates in the given region and year, sorted by date.
+    ///
+    /// Holidays whose date cannot be represented by the date type are skipped.
+    pub fn holiday_dates_in_year_as<D: CalendarDate>(
+        &self,
+        region: GermanRegion,
+        year: i32,
+    ) -> Vec<(D, GermanHoliday)> {
+        let holiday_year = HolidayYear::new(year);
+        let mut holiday_dates = self
+            .holidays_in_year(region, year)
+            .into_iter()
+            .filter_map(|holiday| holiday_year.ymd(holiday).map(|date| (date, holiday)))
+            .collect::<Vec<_>>();
+        holiday_dates.sort_by_key(|&(date, _)| date);
+        holiday_dates
+            .into_iter()
+            .filter_map(|((year, month, day), holiday)| {
+                D::from_ymd(year, month, day).map(|date| (date, holiday))
+            })
+            .collect()
+    }
+
+    /// Returns the public holiday on the given date in the given region, if any.
+    pub fn holiday_on<D: CalendarDate>(
+        &self,
+        region: GermanRegion,
+        date: &D,
+    ) -> Option<GermanHoliday> {
+        let (year, month, day) = date.to_ymd();
+        let holiday_year = HolidayYear::new(year);
+        self.holidays_in_year(region, year)
+            .into_iter()
+            .find(|&holiday| holiday_year.is_on(holiday, (month, day)))
+    }
+
+    /// True if the given date is a public holiday in the given region.
+    pub fn is_holiday_on<D: CalendarDate>(&self, region: GermanRegion, date: &D) -> bool {
+        self.holiday_on(region, date).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn builtin_calendar_matches_regions() {
+        let calendar = HolidayCalendar::builtin();
+        for &region in GermanRegion::ALL {
+            for year in 1985..2100 {
+                assert_eq!(
+                    calendar.holidays_in_year(region, year),
+                    region.holidays_in_year(year)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn additional_rule() {
+        let mut calendar = HolidayCalendar::builtin();
+        calendar
+            .rules
+            .push(HolidayRule::between(Hessen, Frauentag, 2030, 2030));
+        assert!(calendar.holidays_in_year(Hessen, 2030).contains(&Frauentag));
+        assert!(!calendar.holidays_in_year(Hessen, 2031).contains(&Frauentag));
+        assert!(!calendar.holidays_in_year(Bayern, 2030).contains(&Frauentag));
+    }
+
+    #[test]
+    fn overrides() {
+        let mut calendar = HolidayCalendar::builtin();
+        calendar.nationwide.push(Heiligabend);
+        calendar.remove_holiday(Bayern, MariaeHimmelfahrt);
+        calendar.add_holiday(Hamburg, Fronleichnam);
+        for &region in GermanRegion::ALL {
+            assert!(calendar
+                .holidays_in_year(region, 2024)
+                .contains(&Heiligabend));
+        }
+        assert!(!calendar
+            .holidays_in_year(Bayern, 2024)
+            .contains(&MariaeHimmelfahrt));
+        assert!(calendar
+            .holidays_in_year(Saarland, 2024)
+            .contains(&MariaeHimmelfahrt));
+        assert_eq!(
+            calendar.holiday_type(Hamburg, Fronleichnam, 2024),
+            HolidayType::Public
+        );
+        calendar.add_holiday(Bayern, MariaeHimmelfahrt);
+        assert!(calendar
+            .holidays_in_year(Bayern, 2024)
+            .contains(&MariaeHimmelfahrt));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(HolidayCalendar);
+        let schema = schema.as_value();
+        assert_eq!(schema["required"][0], "nationwide");
+        assert_eq!(schema["required"][1], "rules");
+        assert!(schema["$defs"]["GermanHoliday"]["oneOf"][0]["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|holiday| holiday == "Fronleichnam"));
+        assert!(schema["$defs"]["GermanRegion"].is_object());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json() {
+        let calendar = HolidayCalendar::from_json(
+            r#"{
+                "nationwide": ["Neujahr", "TagDerDeutschenEinheit"],
+                "rules": [
+                    {"region": "Berlin", "holiday": "Frauentag", "valid_from": 2019, "valid_to": null}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            calendar.holidays_in_year(Berlin, 2020),
+            vec![Neujahr, TagDerDeutschenEinheit, Frauentag]
+        );
+        assert_eq!(
+            calendar.holidays_in_year(Berlin, 2018),
+            vec![Neujahr, TagDerDeutschenEinheit]
+        );
+        let builtin = HolidayCalendar::builtin();
+        assert_eq!(
+            HolidayCalendar::from_json(&serde_json::to_string(&builtin).unwrap()).unwrap(),
+            builtin
+        );
+    }
+}
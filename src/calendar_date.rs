@@ -0,0 +1,27 @@
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
+
+/// Minimal interface to a date library, used to compute and check holidays
+/// independent of any particular date type.
+///
+/// Implemented, behind the respective features, for `chrono::NaiveDate`,
+/// `time::Date`, `jiff::civil::Date` and `icu_calendar::Date<Iso>`.
+pub trait CalendarDate: Sized {
+    /// Creates a date from a proleptic Gregorian year, month (1 to 12) and day (1 to 31).
+    ///
+    /// `None` if the date cannot be represented.
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self>;
+
+    /// Returns the proleptic Gregorian year, month (1 to 12) and day (1 to 31).
+    fn to_ymd(&self) -> (i32, u32, u32);
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDate for NaiveDate {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        (self.year(), self.month(), self.day())
+    }
+}
@@ -0,0 +1,127 @@
+use core::convert::TryFrom;
+
+use crate::easter;
+
+// Date arithmetic independent of any date library,
+// based on the algorithms from https://howardhinnant.github.io/date_algorithms.html
+
+/// A proleptic Gregorian date as `(year, month, day)`.
+///
+/// Triples compare chronologically.
+pub(crate) type Ymd = (i32, u32, u32);
+
+/// Number of days since 1970-01-01.
+pub(crate) fn days_from_civil((year, month, day): Ymd) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of `days_from_civil`.
+///
+/// `None` if the year does not fit into an `i32`.
+pub(crate) fn civil_from_days(days: i64) -> Option<Ymd> {
+    let days = days.checked_add(719_468)?;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    Some((i32::try_from(year).ok()?, month as u32, day as u32))
+}
+
+/// Adds a number of days to a date.
+pub(crate) fn add_days(date: Ymd, days: i64) -> Option<Ymd> {
+    civil_from_days(days_from_civil(date).checked_add(days)?)
+}
+
+/// Number of days in the given month.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    let next_month = if month == 12 {
+        (year + 1, 1, 1)
+    } else {
+        (year, month + 1, 1)
+    };
+    (days_from_civil(next_month) - days_from_civil((year, month, 1))) as u32
+}
+
+// generated by the build script, see `build.rs`
+#[cfg(feature = "lookup-tables")]
+include!(concat!(env!("OUT_DIR"), "/easter_table.rs"));
+
+/// Easter sunday in the Gregorian calendar.
+///
+/// `None` outside of the years 1583 to 9999.
+/// With the `lookup-tables` feature, years covered by the precomputed table don't need any computation.
+pub(crate) fn easter_sunday(year: i32) -> Option<Ymd> {
+    #[cfg(feature = "lookup-tables")]
+    {
+        if EASTER_TABLE_YEARS.contains(&year) {
+            let (month, day) = EASTER_SUNDAYS[(year - EASTER_TABLE_YEARS.start()) as usize];
+            return Some((year, u32::from(month), u32::from(day)));
+        }
+    }
+    let (month, day) = easter::gregorian(year)?;
+    Some((year, month, day))
+}
+
+/// Day of the week, starting with monday as 0.
+pub(crate) fn weekday_from_monday(date: Ymd) -> i64 {
+    // 1970-01-01 was a thursday
+    (days_from_civil(date) + 3).rem_euclid(7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, NaiveDate};
+    use proptest::prelude::*;
+
+    proptest! {
+    #[test]
+    fn agrees_with_chrono(days in -90_000_000i64..90_000_000) {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let date = epoch + chrono::Duration::days(days);
+        let ymd = (date.year(), date.month(), date.day());
+        assert_eq!(Some(ymd), civil_from_days(days));
+        assert_eq!(days, days_from_civil(ymd));
+        assert_eq!(i64::from(date.weekday().num_days_from_monday()), weekday_from_monday(ymd));
+    }
+    }
+
+    #[test]
+    fn days_in_month() {
+        assert_eq!(super::days_in_month(2024, 2), 29);
+        assert_eq!(super::days_in_month(2023, 2), 28);
+        assert_eq!(super::days_in_month(1900, 2), 28);
+        assert_eq!(super::days_in_month(2024, 12), 31);
+    }
+
+    #[cfg(feature = "lookup-tables")]
+    #[test]
+    fn easter_table_agrees_with_computation() {
+        for year in EASTER_TABLE_YEARS {
+            let (month, day) = easter::gregorian(year).unwrap();
+            assert_eq!(easter_sunday(year), Some((year, month, day)));
+        }
+    }
+
+    proptest! {
+    #[test]
+    fn civil_from_days_does_not_panic(days: i64) {
+        civil_from_days(days);
+    }
+    }
+}
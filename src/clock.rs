@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+
+/// Source of the current date for helpers like `GermanRegion::is_today_holiday`.
+///
+/// Implement this trait to control "today" in tests.
+pub trait Clock {
+    /// The current civil date.
+    fn today(&self) -> NaiveDate;
+}
+
+/// Clock based on the local system time.
+#[cfg(feature = "clock")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SystemClock;
+
+#[cfg(feature = "clock")]
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        chrono::Local::now().date_naive()
+    }
+}
+
+/// Clock which always returns the same date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedClock(pub NaiveDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.0
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::regions::{holiday_ymds_of_regions, GermanRegion};
+
+impl GermanRegion {
+    /// Returns all public holidays within the given years as CSV for the calendar import of Outlook,
+    /// one all-day appointment per holiday.
+    ///
+    /// Dates are formatted as `M/D/YYYY`, as expected by the English version of Outlook.
+    pub fn to_outlook_csv(&self, years: RangeInclusive<i32>) -> String {
+        let mut csv = String::new();
+        push_record(
+            &mut csv,
+            &[
+                "Subject",
+                "Start Date",
+                "Start Time",
+                "End Date",
+                "End Time",
+                "All day event",
+                "Reminder on/off",
+                "Categories",
+            ],
+        );
+        for year in years {
+            for ((year, month, day), holiday) in self.iter_holiday_ymds(year) {
+                let date = format!("{}/{}/{}", month, day, year);
+                push_record(
+                    &mut csv,
+                    &[
+                        holiday.description(),
+                        &date,
+                        "",
+                        &date,
+                        "",
+                        "True",
+                        "False",
+                        "Feiertag",
+                    ],
+                );
+            }
+        }
+        csv
+    }
+}
+
+/// Returns all public holidays of the given regions within the given years as CSV
+/// with the columns `date`, `holiday`, `name` and `regions`, sorted by date.
+///
+/// `regions` lists the ISO 3166-2 codes of all given regions in which the holiday applies,
+/// separated by `;`.
+pub fn to_csv(regions: &[GermanRegion], years: RangeInclusive<i32>) -> String {
+    let mut csv = String::new();
+    push_csv_header(&mut csv);
+    for year in years {
+        push_csv_year(&mut csv, regions, year);
+    }
+    csv
+}
+
+pub(crate) fn push_csv_header(csv: &mut String) {
+    push_record(csv, &["date", "holiday", "name", "regions"]);
+}
+
+/// Appends the records of a single year, see `to_csv`.
+pub(crate) fn push_csv_year(csv: &mut String, regions: &[GermanRegion], year: i32) {
+    for ((year, month, day), holiday, regions) in holiday_ymds_of_regions(regions, year) {
+        let codes = regions
+            .iter()
+            .map(GermanRegion::iso_code)
+            .collect::<Vec<_>>();
+        push_record(
+            csv,
+            &[
+                &format!("{:04}-{:02}-{:02}", year, month, day),
+                &format!("{:?}", holiday),
+                holiday.description(),
+                &codes.join(";"),
+            ],
+        );
+    }
+}
+
+/// Appends a CSV record, quoting fields as described in RFC 4180.
+fn push_record(csv: &mut String, fields: &[&str]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn to_outlook_csv() {
+        let csv = Saarland.to_outlook_csv(2024..=2024);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 13);
+        assert_eq!(
+            lines[0],
+            "Subject,Start Date,Start Time,End Date,End Time,All day event,Reminder on/off,Categories"
+        );
+        assert_eq!(
+            lines[8],
+            "Mariä Himmelfahrt,8/15/2024,,8/15/2024,,True,False,Feiertag"
+        );
+    }
+
+    #[test]
+    fn to_csv() {
+        let csv = super::to_csv(&[Bayern, Sachsen], 2024..=2025);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "date,holiday,name,regions");
+        assert_eq!(lines[1], "2024-01-01,Neujahr,Neujahr,DE-BY;DE-SN");
+        assert_eq!(
+            lines[2],
+            "2024-01-06,HeiligeDreiKoenige,Heilige Drei Könige,DE-BY"
+        );
+        assert!(lines.contains(&"2025-11-19,BussUndBettag,Buß- und Bettag,DE-SN"));
+        assert!(lines[1..].windows(2).all(|w| w[0][..10] <= w[1][..10]));
+    }
+
+    #[test]
+    fn quoting() {
+        let mut csv = String::new();
+        push_record(&mut csv, &["a", "b,c", "d\"e", ""]);
+        assert_eq!(csv, "a,\"b,c\",\"d\"\"e\",\r\n");
+    }
+}
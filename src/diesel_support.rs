@@ -0,0 +1,194 @@
+use core::convert::TryFrom;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{SmallInt, Text};
+
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+// Regions are stored as their ISO 3166-2 code, holidays as the name of their variant.
+// As `SmallInt`, both are stored as their index within `ALL` (`ALL_WITH_BUNDESWEIT` for regions),
+// which is only ever appended to.
+
+impl<DB: Backend> ToSql<Text, DB> for GermanRegion
+where
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.iso_code().to_sql(out)
+    }
+}
+
+impl<DB: Backend> FromSql<Text, DB> for GermanRegion
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let code = String::from_sql(bytes)?;
+        GermanRegion::ALL_WITH_BUNDESWEIT
+            .iter()
+            .copied()
+            .find(|region| region.iso_code() == code)
+            .ok_or_else(|| format!("unknown region code: {}", code).into())
+    }
+}
+
+impl<DB: Backend> ToSql<SmallInt, DB> for GermanRegion
+where
+    i16: ToSql<SmallInt, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        index_to_sql(GermanRegion::ALL_WITH_BUNDESWEIT, self, out)
+    }
+}
+
+impl<DB: Backend> FromSql<SmallInt, DB> for GermanRegion
+where
+    i16: FromSql<SmallInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        index_from_sql(GermanRegion::ALL_WITH_BUNDESWEIT, bytes)
+    }
+}
+
+impl<DB: Backend> ToSql<Text, DB> for GermanHoliday
+where
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.identifier().to_sql(out)
+    }
+}
+
+impl<DB: Backend> FromSql<Text, DB> for GermanHoliday
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let identifier = String::from_sql(bytes)?;
+        GermanHoliday::ALL
+            .iter()
+            .copied()
+            .find(|holiday| holiday.identifier() == identifier)
+            .ok_or_else(|| format!("unknown holiday: {}", identifier).into())
+    }
+}
+
+impl<DB: Backend> ToSql<SmallInt, DB> for GermanHoliday
+where
+    i16: ToSql<SmallInt, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        index_to_sql(GermanHoliday::ALL, self, out)
+    }
+}
+
+impl<DB: Backend> FromSql<SmallInt, DB> for GermanHoliday
+where
+    i16: FromSql<SmallInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        index_from_sql(GermanHoliday::ALL, bytes)
+    }
+}
+
+/// Every index as an `i16`, since `ToSql` needs a value which outlives the output.
+static INDICES: [i16; 64] = {
+    let mut indices = [0; 64];
+    let mut i = 0;
+    while i < indices.len() {
+        indices[i] = i as i16;
+        i += 1;
+    }
+    indices
+};
+
+const _: () = assert!(
+    GermanRegion::ALL_WITH_BUNDESWEIT.len() <= INDICES.len()
+        && GermanHoliday::ALL.len() <= INDICES.len()
+);
+
+fn index_to_sql<'b, T: PartialEq, DB: Backend>(
+    all: &'static [T],
+    value: &T,
+    out: &mut Output<'b, '_, DB>,
+) -> serialize::Result
+where
+    i16: ToSql<SmallInt, DB>,
+{
+    let index = all
+        .iter()
+        .position(|candidate| candidate == value)
+        .expect("value missing from ALL");
+    INDICES[index].to_sql(out)
+}
+
+fn index_from_sql<T: Copy, DB: Backend>(
+    all: &'static [T],
+    bytes: DB::RawValue<'_>,
+) -> deserialize::Result<T>
+where
+    i16: FromSql<SmallInt, DB>,
+{
+    let index = i16::from_sql(bytes)?;
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| all.get(index))
+        .copied()
+        .ok_or_else(|| format!("index out of range: {}", index).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::dsl::sql;
+    use diesel::prelude::*;
+    use diesel::sqlite::SqliteConnection;
+
+    fn connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").unwrap()
+    }
+
+    #[test]
+    fn text() {
+        let conn = &mut connection();
+        let region: GermanRegion = diesel::select(GermanRegion::Bayern.into_sql::<Text>())
+            .get_result(conn)
+            .unwrap();
+        assert_eq!(region, GermanRegion::Bayern);
+        let code: String = diesel::select(GermanRegion::Bayern.into_sql::<Text>())
+            .get_result(conn)
+            .unwrap();
+        assert_eq!(code, "DE-BY");
+        let holiday: GermanHoliday = diesel::select(sql::<Text>("'Fronleichnam'"))
+            .get_result(conn)
+            .unwrap();
+        assert_eq!(holiday, GermanHoliday::Fronleichnam);
+        assert!(diesel::select(sql::<Text>("'Bayern'"))
+            .get_result::<GermanRegion>(conn)
+            .is_err());
+    }
+
+    #[test]
+    fn small_int() {
+        let conn = &mut connection();
+        for &holiday in GermanHoliday::ALL {
+            let roundtrip: GermanHoliday = diesel::select(holiday.into_sql::<SmallInt>())
+                .get_result(conn)
+                .unwrap();
+            assert_eq!(roundtrip, holiday);
+        }
+        let region: GermanRegion = diesel::select(sql::<SmallInt>("15"))
+            .get_result(conn)
+            .unwrap();
+        assert_eq!(region, GermanRegion::Thueringen);
+        let region: GermanRegion = diesel::select(sql::<SmallInt>("16"))
+            .get_result(conn)
+            .unwrap();
+        assert_eq!(region, GermanRegion::Bundesweit);
+        assert!(diesel::select(sql::<SmallInt>("17"))
+            .get_result::<GermanRegion>(conn)
+            .is_err());
+    }
+}
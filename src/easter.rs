@@ -0,0 +1,39 @@
+// Computation of easter sunday without any dependencies,
+// also included by the build script to generate the lookup table of the `lookup-tables` feature.
+
+/// Month and day of easter sunday in the Gregorian calendar, using the anonymous Gregorian algorithm.
+///
+/// `None` outside of the years 1583 to 9999.
+pub(crate) fn gregorian(year: i32) -> Option<(u32, u32)> {
+    if !(1583..=9999).contains(&year) {
+        return None;
+    }
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Some((month as u32, day as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    proptest! {
+    #[test]
+    fn agrees_with_computus(year in 1000i32..11000) {
+        let expected = computus::gregorian(year).ok().map(|date| (date.month, date.day));
+        assert_eq!(super::gregorian(year), expected);
+    }
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Errors returned by the fallible `try_*` query variants.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HolidayError {
+    /// The year lies outside of `supported_year_range()`.
+    #[error("public holidays are only supported from 1990 to 9999, got year {0}")]
+    UnsupportedYear(i32),
+}
+
+/// Error returned when converting a `GermanRegion` which is not a federal state, i.e. `Bundesweit`,
+/// into a `Bundesland`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[error("region is not a federal state")]
+pub struct NotABundeslandError;
+
+/// Error returned when parsing a `GermanRegion` from a string fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[error("unknown German region")]
+pub struct ParseRegionError;
@@ -0,0 +1,382 @@
+use core::ops::RangeInclusive;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::civil;
+use crate::holidays::GermanHoliday;
+use crate::ics::event_uid;
+use crate::regions::GermanRegion;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NagerDateHoliday {
+    date: String,
+    local_name: &'static str,
+    name: &'static str,
+    country_code: &'static str,
+    fixed: bool,
+    global: bool,
+    counties: Option<Vec<&'static str>>,
+    launch_year: Option<i32>,
+    types: [&'static str; 1],
+}
+
+/// Returns all public holidays in Germany in the given year as JSON
+/// in the schema of the Nager.Date public holiday API.
+///
+/// Holidays which only apply in some regions list the ISO 3166-2 codes of those regions as `counties`.
+pub fn nager_date_json(year: i32) -> String {
+    let mut holidays = Vec::new();
+    for &holiday in GermanHoliday::ALL {
+        let ymd = match holiday.ymd(year) {
+            Some(ymd) => ymd,
+            None => continue,
+        };
+        let regions = regions_with_holiday(holiday, year);
+        if regions.is_empty() {
+            continue;
+        }
+        let global = regions.len() == GermanRegion::ALL.len();
+        holidays.push((
+            ymd,
+            NagerDateHoliday {
+                date: format_ymd(ymd),
+                local_name: holiday.description(),
+                name: holiday.english_name(),
+                country_code: "DE",
+                fixed: holiday.fixed_date().is_some(),
+                global,
+                counties: if global {
+                    None
+                } else {
+                    Some(regions.iter().map(GermanRegion::iso_code).collect())
+                },
+                launch_year: None,
+                types: ["Public"],
+            },
+        ));
+    }
+    holidays.sort_by_key(|&(ymd, _)| ymd);
+    let holidays = holidays
+        .into_iter()
+        .map(|(_, holiday)| holiday)
+        .collect::<Vec<_>>();
+    serde_json::to_string(&holidays).expect("failed to serialize holidays")
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenHolidaysHoliday {
+    id: String,
+    start_date: String,
+    end_date: String,
+    #[serde(rename = "type")]
+    holiday_type: &'static str,
+    name: [OpenHolidaysText; 2],
+    nationwide: bool,
+    subdivisions: Vec<OpenHolidaysSubdivision>,
+}
+
+#[derive(Serialize)]
+struct OpenHolidaysText {
+    language: &'static str,
+    text: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenHolidaysSubdivision {
+    code: &'static str,
+    short_name: &'static str,
+}
+
+impl GermanRegion {
+    /// Returns the public holidays in the given year as JSON in the schema of the OpenHolidays API.
+    ///
+    /// The `id` of each holiday is stable across calls, e.g. `"DE-BY-2024-Fronleichnam"`.
+    pub fn open_holidays_json(&self, year: i32) -> String {
+        let holidays = self
+            .iter_holiday_ymds(year)
+            .map(|(ymd, holiday)| {
+                let nationwide =
+                    regions_with_holiday(holiday, year).len() == GermanRegion::ALL.len();
+                OpenHolidaysHoliday {
+                    id: format!("{}-{}-{:?}", self.iso_code(), year, holiday),
+                    start_date: format_ymd(ymd),
+                    end_date: format_ymd(ymd),
+                    holiday_type: "Public",
+                    name: [
+                        OpenHolidaysText {
+                            language: "DE",
+                            text: holiday.description(),
+                        },
+                        OpenHolidaysText {
+                            language: "EN",
+                            text: holiday.english_name(),
+                        },
+                    ],
+                    nationwide,
+                    subdivisions: if nationwide {
+                        Vec::new()
+                    } else {
+                        vec![OpenHolidaysSubdivision {
+                            code: self.iso_code(),
+                            short_name: self.short_code(),
+                        }]
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string(&holidays).expect("failed to serialize holidays")
+    }
+}
+
+#[derive(Serialize)]
+struct FeiertageApiHoliday {
+    datum: String,
+    hinweis: &'static str,
+}
+
+/// Map which keeps the order of its entries when serialized.
+struct OrderedMap<K, V>(Vec<(K, V)>);
+
+impl<K: Serialize, V: Serialize> Serialize for OrderedMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl GermanRegion {
+    fn feiertage_api_holidays(&self, year: i32) -> OrderedMap<&'static str, FeiertageApiHoliday> {
+        OrderedMap(
+            self.iter_holiday_ymds(year)
+                .map(|(ymd, holiday)| {
+                    let hinweis = match (self, holiday) {
+                        (GermanRegion::Bayern, GermanHoliday::MariaeHimmelfahrt) => {
+                            "Mariä Himmelfahrt ist nur in Gemeinden mit überwiegend katholischer Bevölkerung ein gesetzlicher Feiertag."
+                        }
+                        _ => "",
+                    };
+                    (
+                        holiday.description(),
+                        FeiertageApiHoliday {
+                            datum: format_ymd(ymd),
+                            hinweis,
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the public holidays in the given year as JSON in the response format of feiertage-api.de,
+    /// i.e. an object mapping the name of each holiday to its `datum` and `hinweis`.
+    pub fn feiertage_api_json(&self, year: i32) -> String {
+        serde_json::to_string(&self.feiertage_api_holidays(year))
+            .expect("failed to serialize holidays")
+    }
+}
+
+/// Returns the public holidays of all regions in the given year as JSON
+/// in the response format of feiertage-api.de, keyed by the abbreviation of each region.
+pub fn feiertage_api_json(year: i32) -> String {
+    let regions = OrderedMap(
+        GermanRegion::ALL
+            .iter()
+            .map(|region| (region.short_code(), region.feiertage_api_holidays(year)))
+            .collect(),
+    );
+    serde_json::to_string(&regions).expect("failed to serialize holidays")
+}
+
+#[derive(Serialize)]
+struct GoogleCalendarEvent {
+    id: String,
+    #[serde(rename = "iCalUID")]
+    ical_uid: String,
+    summary: &'static str,
+    start: GoogleCalendarDate,
+    end: GoogleCalendarDate,
+    transparency: &'static str,
+}
+
+#[derive(Serialize)]
+struct GoogleCalendarDate {
+    date: String,
+}
+
+impl GermanRegion {
+    /// Returns all public holidays within the given years as a JSON array of
+    /// Google Calendar API event resources.
+    ///
+    /// The `id` of each event is derived from its stable UID (see `to_ics`),
+    /// so inserting an event which already exists fails and it can be updated instead of duplicated.
+    /// Sending the requests is left to the caller.
+    pub fn google_calendar_json(&self, years: RangeInclusive<i32>) -> String {
+        let events = years
+            .flat_map(|year| self.iter_holiday_ymds(year))
+            .filter_map(|(date, holiday)| {
+                let uid = event_uid(*self, holiday, date);
+                Some(GoogleCalendarEvent {
+                    id: base32hex(uid.as_bytes()),
+                    ical_uid: uid,
+                    summary: holiday.description(),
+                    start: GoogleCalendarDate {
+                        date: format_ymd(date),
+                    },
+                    end: GoogleCalendarDate {
+                        date: format_ymd(civil::add_days(date, 1)?),
+                    },
+                    transparency: "transparent",
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string(&events).expect("failed to serialize events")
+    }
+}
+
+/// Encodes bytes as lowercase base32hex without padding,
+/// the character set allowed in Google Calendar event IDs.
+fn base32hex(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer = 0u16;
+    let mut bits = 0;
+    for &byte in bytes {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(char::from(ALPHABET[usize::from((buffer >> bits) & 0x1f)]));
+        }
+    }
+    if bits > 0 {
+        encoded.push(char::from(
+            ALPHABET[usize::from((buffer << (5 - bits)) & 0x1f)],
+        ));
+    }
+    encoded
+}
+
+fn regions_with_holiday(holiday: GermanHoliday, year: i32) -> Vec<GermanRegion> {
+    GermanRegion::ALL
+        .iter()
+        .copied()
+        .filter(|region| {
+            region
+                .iter_holidays_in_year(year)
+                .any(|public| public == holiday)
+        })
+        .collect()
+}
+
+fn format_ymd((year, month, day): (i32, u32, u32)) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::regions::GermanRegion::*;
+    use serde_json::Value;
+
+    #[test]
+    fn google_calendar_json() {
+        let events: Value =
+            serde_json::from_str(&Hessen.google_calendar_json(2024..=2024)).unwrap();
+        let events = events.as_array().unwrap();
+        assert_eq!(events.len(), 10);
+        assert_eq!(events[0]["iCalUID"], "20240101-Neujahr-DE-HE@holiday_de");
+        assert_eq!(events[0]["start"]["date"], "2024-01-01");
+        assert_eq!(events[0]["end"]["date"], "2024-01-02");
+        let id = events[0]["id"].as_str().unwrap();
+        assert!(id
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='v').contains(&c)));
+    }
+
+    #[test]
+    fn base32hex() {
+        assert_eq!(super::base32hex(b""), "");
+        assert_eq!(super::base32hex(b"f"), "co");
+        assert_eq!(super::base32hex(b"foobar"), "cpnmuoj1e8");
+    }
+
+    #[test]
+    fn feiertage_api_json() {
+        let json = Bayern.feiertage_api_json(2024);
+        assert!(json.starts_with(
+            r#"{"Neujahr":{"datum":"2024-01-01","hinweis":""},"Heilige Drei Könige":{"datum":"2024-01-06","hinweis":""},"#
+        ));
+        let holidays: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(holidays.as_object().unwrap().len(), 13);
+        assert_ne!(holidays["Mariä Himmelfahrt"]["hinweis"], "");
+        let all: Value = serde_json::from_str(&super::feiertage_api_json(2024)).unwrap();
+        assert_eq!(all.as_object().unwrap().len(), 16);
+        assert_eq!(all["BY"], holidays);
+    }
+
+    #[test]
+    fn open_holidays_json() {
+        let holidays: Value = serde_json::from_str(&Bayern.open_holidays_json(2024)).unwrap();
+        let holidays = holidays.as_array().unwrap();
+        assert_eq!(holidays.len(), 13);
+        assert_eq!(
+            holidays[1],
+            serde_json::json!({
+                "id": "DE-BY-2024-HeiligeDreiKoenige",
+                "startDate": "2024-01-06",
+                "endDate": "2024-01-06",
+                "type": "Public",
+                "name": [
+                    {"language": "DE", "text": "Heilige Drei Könige"},
+                    {"language": "EN", "text": "Epiphany"},
+                ],
+                "nationwide": false,
+                "subdivisions": [{"code": "DE-BY", "shortName": "BY"}],
+            })
+        );
+        assert_eq!(holidays[0]["nationwide"], true);
+        assert_eq!(holidays[0]["subdivisions"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn nager_date_json() {
+        let holidays: Value = serde_json::from_str(&super::nager_date_json(2024)).unwrap();
+        let holidays = holidays.as_array().unwrap();
+        assert_eq!(holidays.len(), 17);
+        assert_eq!(
+            holidays[0],
+            serde_json::json!({
+                "date": "2024-01-01",
+                "localName": "Neujahr",
+                "name": "New Year's Day",
+                "countryCode": "DE",
+                "fixed": true,
+                "global": true,
+                "counties": null,
+                "launchYear": null,
+                "types": ["Public"],
+            })
+        );
+        assert_eq!(
+            holidays[1],
+            serde_json::json!({
+                "date": "2024-01-06",
+                "localName": "Heilige Drei Könige",
+                "name": "Epiphany",
+                "countryCode": "DE",
+                "fixed": true,
+                "global": false,
+                "counties": ["DE-BW", "DE-BY", "DE-ST"],
+                "launchYear": null,
+                "types": ["Public"],
+            })
+        );
+        assert_eq!(holidays[4]["localName"], "Ostermontag");
+        assert_eq!(holidays[4]["fixed"], false);
+    }
+}
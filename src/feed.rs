@@ -0,0 +1,78 @@
+use alloc::format;
+use alloc::string::String;
+use chrono::{Datelike, NaiveDate};
+
+use crate::ics::event_uid;
+use crate::regions::GermanRegion;
+
+impl GermanRegion {
+    /// Returns an Atom feed (RFC 4287) of the next `count` public holidays on or after `today`,
+    /// with one entry per holiday.
+    ///
+    /// Entry IDs are stable, so feed readers do not show a holiday twice when the feed is regenerated.
+    pub fn atom_feed(&self, today: NaiveDate, count: usize) -> String {
+        let mut feed = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        feed.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        feed.push_str(&format!(
+            "  <id>tag:holiday_de,2024:{}</id>\n",
+            self.iso_code()
+        ));
+        feed.push_str(&format!(
+            "  <title>Feiertage ({})</title>\n",
+            self.iso_code()
+        ));
+        feed.push_str(&format!("  <updated>{}T00:00:00Z</updated>\n", today));
+        feed.push_str("  <author><name>holiday_de</name></author>\n");
+        for (date, holiday) in self.iter_holidays_from(today).take(count) {
+            let title = escape_xml(holiday.description());
+            feed.push_str("  <entry>\n");
+            feed.push_str(&format!(
+                "    <id>tag:holiday_de,2024:{}</id>\n",
+                event_uid(*self, holiday, (date.year(), date.month(), date.day()))
+            ));
+            feed.push_str(&format!("    <title>{}</title>\n", title));
+            feed.push_str(&format!("    <updated>{}T00:00:00Z</updated>\n", today));
+            feed.push_str(&format!(
+                "    <content type=\"text\">{:02}.{:02}.{:04}: {}</content>\n",
+                date.day(),
+                date.month(),
+                date.year(),
+                title
+            ));
+            feed.push_str("  </entry>\n");
+        }
+        feed.push_str("</feed>\n");
+        feed
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn atom_feed() {
+        let feed = Hessen.atom_feed(NaiveDate::from_ymd_opt(2024, 10, 3).unwrap(), 3);
+        assert!(feed.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed"));
+        assert!(feed.ends_with("</feed>\n"));
+        assert_eq!(feed.matches("<entry>").count(), 3);
+        assert!(feed.contains(
+            "    <id>tag:holiday_de,2024:20241003-TagDerDeutschenEinheit-DE-HE@holiday_de</id>\n    <title>Tag der Deutschen Einheit</title>\n"
+        ));
+        assert!(feed
+            .contains("<content type=\"text\">26.12.2024: Zweiter Weihnachtsfeiertag</content>"));
+    }
+
+    #[test]
+    fn escape_xml() {
+        assert_eq!(super::escape_xml("a & <b>\""), "a &amp; &lt;b&gt;&quot;");
+    }
+}
@@ -0,0 +1,236 @@
+use async_graphql::{
+    EmptyMutation, EmptySubscription, Error, Object, Result, Schema, SimpleObject,
+};
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+
+use crate::regions::GermanRegion;
+
+/// The schema returned by `schema`.
+pub type HolidaySchema = Schema<HolidayQuery, EmptyMutation, EmptySubscription>;
+
+/// Returns a schema containing only `HolidayQuery`.
+///
+/// To add the queries to an existing schema, merge `HolidayQuery` into its query root
+/// using `async_graphql::MergedObject`.
+pub fn schema() -> HolidaySchema {
+    Schema::new(HolidayQuery, EmptyMutation, EmptySubscription)
+}
+
+/// GraphQL queries for regions, public holidays and working days.
+///
+/// Regions are given as strings parsed with `GermanRegion::from_str`, e.g. `"DE-BY"` or `"Bayern"`,
+/// dates as ISO 8601 strings (`YYYY-MM-DD`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HolidayQuery;
+
+/// A German federal state.
+#[derive(SimpleObject)]
+struct Region {
+    /// ISO 3166-2 code, e.g. `DE-BY`.
+    code: &'static str,
+    /// German name, e.g. `Bayern`.
+    name: &'static str,
+}
+
+impl From<GermanRegion> for Region {
+    fn from(region: GermanRegion) -> Self {
+        Self {
+            code: region.iso_code(),
+            name: region.name(),
+        }
+    }
+}
+
+/// A public holiday on a specific date.
+#[derive(SimpleObject)]
+struct Holiday {
+    date: String,
+    /// Identifier of the holiday, e.g. `TagDerDeutschenEinheit`.
+    holiday: String,
+    /// German name of the holiday, e.g. `Tag der Deutschen Einheit`.
+    name: &'static str,
+}
+
+#[Object]
+impl HolidayQuery {
+    /// All German federal states.
+    async fn regions(&self) -> Vec<Region> {
+        GermanRegion::ALL
+            .iter()
+            .map(|&region| region.into())
+            .collect()
+    }
+
+    /// All public holidays of a region in a year, sorted by date.
+    async fn holidays(&self, region: String, year: i32) -> Result<Vec<Holiday>> {
+        let region = parse_region(&region)?;
+        region.try_holidays_in_year(year)?;
+        Ok(region
+            .iter_holiday_dates(year)
+            .map(|(date, holiday)| Holiday {
+                date: date.to_string(),
+                holiday: format!("{:?}", holiday),
+                name: holiday.description(),
+            })
+            .collect())
+    }
+
+    /// The public holiday on a date in a region, if any.
+    async fn holiday(&self, region: String, date: String) -> Result<Option<Holiday>> {
+        let region = parse_region(&region)?;
+        let date = parse_date(&date)?;
+        Ok(region.try_holiday_from_date(date)?.map(|holiday| Holiday {
+            date: date.to_string(),
+            holiday: format!("{:?}", holiday),
+            name: holiday.description(),
+        }))
+    }
+
+    /// Whether a date is a public holiday in a region.
+    async fn is_holiday(&self, region: String, date: String) -> Result<bool> {
+        let region = parse_region(&region)?;
+        Ok(region.try_holiday_from_date(parse_date(&date)?)?.is_some())
+    }
+
+    /// Whether a date is neither a weekend nor a public holiday in a region.
+    async fn is_working_day(&self, region: String, date: String) -> Result<bool> {
+        let region = parse_region(&region)?;
+        is_working_day(region, parse_date(&date)?)
+    }
+
+    /// Number of working days from `from` to `to`, both inclusive.
+    async fn working_days(&self, region: String, from: String, to: String) -> Result<u32> {
+        let region = parse_region(&region)?;
+        let (from, to) = (parse_date(&from)?, parse_date(&to)?);
+        count_working_days(region, from, to)
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn is_working_day(region: GermanRegion, date: NaiveDate) -> Result<bool> {
+    Ok(!is_weekend(date) && region.try_holiday_from_date(date)?.is_none())
+}
+
+/// Counts the weekdays in the range and subtracts the holidays on weekdays year by year,
+/// so that the cost depends on the number of years rather than days.
+fn count_working_days(region: GermanRegion, from: NaiveDate, to: NaiveDate) -> Result<u32> {
+    if to < from {
+        return Ok(0);
+    }
+    let mut holidays_on_weekdays = BTreeSet::new();
+    for year in from.year()..=to.year() {
+        // fails for unsupported years
+        region.try_holidays_in_year(year)?;
+        holidays_on_weekdays.extend(
+            region
+                .iter_holiday_dates(year)
+                .map(|(date, _)| date)
+                .filter(|&date| from <= date && date <= to && !is_weekend(date)),
+        );
+    }
+    let days = (to - from).num_days() + 1;
+    let remaining_weekdays = from
+        .iter_days()
+        .take((days % 7) as usize)
+        .filter(|&date| !is_weekend(date))
+        .count() as i64;
+    let weekdays = days / 7 * 5 + remaining_weekdays;
+    Ok((weekdays - holidays_on_weekdays.len() as i64) as u32)
+}
+
+fn parse_region(input: &str) -> Result<GermanRegion> {
+    input
+        .parse()
+        .map_err(|_| Error::new(format!("unknown region: {}", input)))
+}
+
+fn parse_date(input: &str) -> Result<NaiveDate> {
+    input
+        .parse()
+        .map_err(|_| Error::new(format!("invalid date, expected YYYY-MM-DD: {}", input)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn execute(query: &str) -> async_graphql::Response {
+        schema().execute(query).await
+    }
+
+    #[tokio::test]
+    async fn holidays() {
+        let response =
+            execute(r#"{ holidays(region: "Berlin", year: 2025) { date holiday } }"#).await;
+        assert!(response.errors.is_empty());
+        let json = response.data.into_json().unwrap();
+        let holidays = json["holidays"].as_array().unwrap();
+        assert_eq!(holidays.len(), 11);
+        assert_eq!(holidays[2]["date"], "2025-04-18");
+        assert_eq!(holidays[2]["holiday"], "Karfreitag");
+    }
+
+    #[tokio::test]
+    async fn working_days() {
+        let response = execute(
+            r#"{
+                christmas: isWorkingDay(region: "DE-BY", date: "2024-12-25")
+                monday: isWorkingDay(region: "DE-BY", date: "2024-12-23")
+                december: workingDays(region: "DE-BY", from: "2024-12-01", to: "2024-12-31")
+                regions { code }
+            }"#,
+        )
+        .await;
+        assert!(response.errors.is_empty());
+        let json = response.data.into_json().unwrap();
+        assert_eq!(json["christmas"], false);
+        assert_eq!(json["monday"], true);
+        assert_eq!(json["december"], 20);
+        assert_eq!(json["regions"].as_array().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn working_days_match_day_by_day_count() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        for (from, to) in [
+            (date(2019, 12, 20), date(2025, 1, 7)),
+            (date(2008, 4, 30), date(2008, 5, 2)),
+            (date(2024, 12, 28), date(2024, 12, 29)),
+            (date(2024, 12, 2), date(2024, 12, 1)),
+        ] {
+            let expected = from
+                .iter_days()
+                .take_while(|&date| date <= to)
+                .filter(|&date| is_working_day(GermanRegion::Bayern, date).unwrap())
+                .count() as u32;
+            assert_eq!(
+                count_working_days(GermanRegion::Bayern, from, to).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn working_days_over_all_supported_years() {
+        let response =
+            execute(r#"{ workingDays(region: "DE-BY", from: "1990-01-01", to: "9999-12-31") }"#)
+                .await;
+        assert!(response.errors.is_empty());
+        let response =
+            execute(r#"{ workingDays(region: "DE-BY", from: "1989-12-01", to: "2024-12-31") }"#)
+                .await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_input() {
+        let response = execute(r#"{ isHoliday(region: "Bavaria", date: "2024-01-01") }"#).await;
+        assert_eq!(response.errors[0].message, "unknown region: Bavaria");
+        let response = execute(r#"{ holidays(region: "BY", year: 1989) { date } }"#).await;
+        assert_eq!(response.errors.len(), 1);
+    }
+}
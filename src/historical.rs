@@ -0,0 +1,160 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::GermanHoliday::*;
+use crate::holidays::{GermanHoliday, HolidayYear};
+use crate::regions::GermanRegion;
+use crate::regions::GermanRegion::*;
+use crate::regions::FIRST_YEAR;
+
+impl GermanRegion {
+    /// True for regions which were part of West Germany before 1990, including West Berlin.
+    pub(crate) fn is_west_german(&self) -> bool {
+        !matches!(
+            self,
+            Brandenburg | MechlenburgVorpommern | Sachsen | SachsenAnhalt | Thueringen | Bundesweit
+        )
+    }
+
+    /// Returns all public holidays in the given year, including years before 1990.
+    ///
+    /// Before 1990, only regions of West Germany are covered, starting with 1954,
+    /// when the 17th of June was first observed as Tag der deutschen Einheit.
+    /// Saarland is covered from 1957 on, `Berlin` refers to West Berlin.
+    /// In Bayern, Buß- und Bettag only applied to protestant communities before 1981
+    /// and is excluded by default for those years.
+    ///
+    /// For other years and regions this list will be empty.
+    pub fn historical_holidays_in_year(&self, year: i32) -> Vec<GermanHoliday> {
+        if year >= FIRST_YEAR {
+            return self.holidays_in_year(year);
+        }
+        if !self.is_west_german() || year < 1954 || (*self == Saarland && year < 1957) {
+            return Vec::new();
+        }
+        let mut holidays = vec![
+            Neujahr,
+            Karfreitag,
+            Ostermontag,
+            ErsterMai,
+            ChristiHimmelfahrt,
+            Pfingstmontag,
+            SiebzehnterJuni,
+            ErsterWeihnachtsfeiertag,
+            ZweiterWeihnachtsfeiertag,
+        ];
+        holidays.extend(self.region_specific_holidays(year));
+        if *self != Bayern || year >= 1981 {
+            holidays.push(BussUndBettag);
+        }
+        holidays
+    }
+
+    /// Returns the holiday for a specific date if the date is a holiday in the specific region,
+    /// including dates before 1990, see `historical_holidays_in_year`.
+    pub fn historical_holiday_on<D: CalendarDate>(&self, date: &D) -> Option<GermanHoliday> {
+        let (year, month, day) = date.to_ymd();
+        let holiday_year = HolidayYear::new(year);
+        self.historical_holidays_in_year(year)
+            .into_iter()
+            .find(|&holiday| holiday_year.is_on(holiday, (month, day)))
+    }
+}
+
+/// The German Democratic Republic (DDR) from 1950 to 1989.
+///
+/// Its territory corresponds to the regions Brandenburg, Mecklenburg-Vorpommern, Sachsen,
+/// Sachsen-Anhalt and Thüringen as well as East Berlin.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Ddr;
+
+impl Ddr {
+    /// Returns all public holidays in the given year.
+    ///
+    /// Ostermontag, Christi Himmelfahrt, Reformationstag, Buß- und Bettag and
+    /// Tag der Befreiung were abolished in 1967.
+    /// Tag der Befreiung was reinstated once in 1985, Tag des Sieges was a holiday once in 1975.
+    ///
+    /// For years outside of 1950 to 1989 this list will be empty.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<GermanHoliday> {
+        if !(1950..=1989).contains(&year) {
+            return Vec::new();
+        }
+        let mut holidays = vec![Neujahr, Karfreitag];
+        if year < 1967 {
+            holidays.extend_from_slice(&[
+                Ostermontag,
+                ErsterMai,
+                TagDerBefreiung,
+                ChristiHimmelfahrt,
+            ]);
+        } else {
+            holidays.push(ErsterMai);
+        }
+        match year {
+            1975 => holidays.push(TagDesSieges),
+            1985 => holidays.push(TagDerBefreiung),
+            _ => {}
+        }
+        holidays.extend_from_slice(&[Pfingstmontag, TagDerRepublik]);
+        if year < 1967 {
+            holidays.extend_from_slice(&[Reformationstag, BussUndBettag]);
+        }
+        holidays.extend_from_slice(&[ErsterWeihnachtsfeiertag, ZweiterWeihnachtsfeiertag]);
+        holidays
+    }
+
+    /// Returns the holiday for a specific date if the date was a public holiday in the GDR.
+    pub fn holiday_on<D: CalendarDate>(&self, date: &D) -> Option<GermanHoliday> {
+        let (year, month, day) = date.to_ymd();
+        let holiday_year = HolidayYear::new(year);
+        self.holidays_in_year(year)
+            .into_iter()
+            .find(|&holiday| holiday_year.is_on(holiday, (month, day)))
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn west_german_holidays() {
+        let date = NaiveDate::from_ymd_opt(1975, 6, 17).unwrap();
+        assert_eq!(Some(SiebzehnterJuni), Hessen.historical_holiday_on(&date));
+        assert_eq!(Some(SiebzehnterJuni), Berlin.historical_holiday_on(&date));
+        assert_eq!(None, Sachsen.historical_holiday_on(&date));
+        let date = NaiveDate::from_ymd_opt(1975, 10, 3).unwrap();
+        assert_eq!(None, Hessen.historical_holiday_on(&date));
+        assert!(Saarland.historical_holidays_in_year(1956).is_empty());
+        assert!(Hamburg.historical_holidays_in_year(1953).is_empty());
+        assert!(!Bayern
+            .historical_holidays_in_year(1980)
+            .contains(&BussUndBettag));
+        assert!(Bayern
+            .historical_holidays_in_year(1981)
+            .contains(&BussUndBettag));
+        assert_eq!(
+            Bayern.holidays_in_year(2019),
+            Bayern.historical_holidays_in_year(2019)
+        );
+    }
+
+    #[test]
+    fn ddr_holidays() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(Some(TagDerRepublik), Ddr.holiday_on(&date(1989, 10, 7)));
+        assert_eq!(None, Ddr.holiday_on(&date(1989, 6, 17)));
+        assert_eq!(Some(TagDerBefreiung), Ddr.holiday_on(&date(1960, 5, 8)));
+        assert_eq!(None, Ddr.holiday_on(&date(1970, 5, 8)));
+        assert_eq!(Some(TagDerBefreiung), Ddr.holiday_on(&date(1985, 5, 8)));
+        assert_eq!(Some(TagDesSieges), Ddr.holiday_on(&date(1975, 5, 9)));
+        assert_eq!(Some(Reformationstag), Ddr.holiday_on(&date(1966, 10, 31)));
+        assert_eq!(None, Ddr.holiday_on(&date(1967, 10, 31)));
+        assert_eq!(12, Ddr.holidays_in_year(1955).len());
+        assert_eq!(7, Ddr.holidays_in_year(1970).len());
+        assert!(Ddr.holidays_in_year(1990).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::{self, GermanHoliday};
+#[cfg(feature = "chrono")]
+use crate::regions::DatedHoliday;
+use crate::regions::GermanRegion;
+
+/// A set of holidays stored as a bitset, supporting set operations without allocating.
+///
+/// Iterates in the order of `GermanHoliday::ALL`, use `iter_dates` for chronological order.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct HolidaySet(u32);
+
+impl HolidaySet {
+    /// The empty set.
+    pub const EMPTY: HolidaySet = HolidaySet(0);
+
+    /// The set of all holidays in `GermanHoliday::ALL`.
+    pub const fn all() -> Self {
+        HolidaySet((1 << GermanHoliday::ALL.len()) - 1)
+    }
+
+    const fn bit(holiday: GermanHoliday) -> u32 {
+        // the variants are declared in the order of `GermanHoliday::ALL`
+        1 << holiday as u32
+    }
+
+    /// True if the holiday is in this set.
+    pub const fn contains(&self, holiday: GermanHoliday) -> bool {
+        self.0 & Self::bit(holiday) != 0
+    }
+
+    /// Adds a holiday to this set, returning whether it was newly added.
+    pub fn insert(&mut self, holiday: GermanHoliday) -> bool {
+        let added = !self.contains(holiday);
+        self.0 |= Self::bit(holiday);
+        added
+    }
+
+    /// Removes a holiday from this set, returning whether it was present.
+    pub fn remove(&mut self, holiday: GermanHoliday) -> bool {
+        let present = self.contains(holiday);
+        self.0 &= !Self::bit(holiday);
+        present
+    }
+
+    /// Holidays in either set.
+    pub const fn union(self, other: HolidaySet) -> Self {
+        HolidaySet(self.0 | other.0)
+    }
+
+    /// Holidays in both sets.
+    pub const fn intersection(self, other: HolidaySet) -> Self {
+        HolidaySet(self.0 & other.0)
+    }
+
+    /// Holidays in this set but not in `other`.
+    pub const fn difference(self, other: HolidaySet) -> Self {
+        HolidaySet(self.0 & !other.0)
+    }
+
+    /// True if all holidays of this set are also in `other`.
+    pub const fn is_subset(&self, other: &HolidaySet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Number of holidays in this set.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// True if this set contains no holidays.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the holidays in this set in the order of `GermanHoliday::ALL`.
+    pub fn iter(&self) -> impl Iterator<Item = GermanHoliday> {
+        let set = *self;
+        GermanHoliday::ALL
+            .iter()
+            .copied()
+            .filter(move |&holiday| set.contains(holiday))
+    }
+
+    /// Returns an iterator over the holidays in this set and their dates in the given year
+    /// as any supported date type, in chronological order.
+    ///
+    /// Holidays whose date cannot be represented by the date type are skipped.
+    pub fn iter_dates_as<D: CalendarDate>(
+        &self,
+        year: i32,
+    ) -> impl DoubleEndedIterator<Item = (D, GermanHoliday)> {
+        holidays::sorted_ymds(self.iter(), year).flat_map(|((year, month, day), holiday)| {
+            D::from_ymd(year, month, day).map(|date| (date, holiday))
+        })
+    }
+
+    /// Returns an iterator over the holidays in this set and their dates in the given year
+    /// in chronological order.
+    #[cfg(feature = "chrono")]
+    pub fn iter_dates(&self, year: i32) -> impl DoubleEndedIterator<Item = DatedHoliday> {
+        self.iter_dates_as(year)
+    }
+}
+
+impl GermanRegion {
+    /// Returns all public holidays in the given year as a `HolidaySet`.
+    ///
+    /// Same as `holidays_in_year`, but without allocating.
+    pub fn holiday_set(&self, year: i32) -> HolidaySet {
+        self.iter_holidays_in_year(year).collect()
+    }
+}
+
+impl fmt::Debug for HolidaySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<GermanHoliday> for HolidaySet {
+    fn from(holiday: GermanHoliday) -> Self {
+        HolidaySet(Self::bit(holiday))
+    }
+}
+
+impl FromIterator<GermanHoliday> for HolidaySet {
+    fn from_iter<I: IntoIterator<Item = GermanHoliday>>(iter: I) -> Self {
+        let mut set = HolidaySet::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<GermanHoliday> for HolidaySet {
+    fn extend<I: IntoIterator<Item = GermanHoliday>>(&mut self, iter: I) {
+        for holiday in iter {
+            self.insert(holiday);
+        }
+    }
+}
+
+impl BitOr for HolidaySet {
+    type Output = HolidaySet;
+
+    fn bitor(self, other: HolidaySet) -> HolidaySet {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for HolidaySet {
+    fn bitor_assign(&mut self, other: HolidaySet) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for HolidaySet {
+    type Output = HolidaySet;
+
+    fn bitand(self, other: HolidaySet) -> HolidaySet {
+        self.intersection(other)
+    }
+}
+
+impl BitAndAssign for HolidaySet {
+    fn bitand_assign(&mut self, other: HolidaySet) {
+        *self = self.intersection(other);
+    }
+}
+
+impl Sub for HolidaySet {
+    type Output = HolidaySet;
+
+    fn sub(self, other: HolidaySet) -> HolidaySet {
+        self.difference(other)
+    }
+}
+
+impl SubAssign for HolidaySet {
+    fn sub_assign(&mut self, other: HolidaySet) {
+        *self = self.difference(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HolidaySet;
+    use crate::holidays::GermanHoliday::{self, *};
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn variants_are_declared_in_order_of_all() {
+        for (index, &holiday) in GermanHoliday::ALL.iter().enumerate() {
+            assert_eq!(holiday as usize, index);
+        }
+        assert_eq!(HolidaySet::all().len(), GermanHoliday::ALL.len());
+        assert!(HolidaySet::all()
+            .iter()
+            .eq(GermanHoliday::ALL.iter().copied()));
+    }
+
+    #[test]
+    fn set_operations() {
+        let bayern = Bayern.holiday_set(2024);
+        let berlin = Berlin.holiday_set(2024);
+        assert!(Bayern
+            .iter_holidays_in_year(2024)
+            .all(|holiday| bayern.contains(holiday)));
+        assert_eq!(bayern.len(), 13);
+        assert_eq!(format!("{:?}", berlin - bayern), "{Frauentag}");
+        assert_eq!(
+            (bayern & berlin).len(),
+            Berlin.iter_holidays_in_year(2024).count() - 1
+        );
+        assert_eq!(bayern | berlin, bayern.union(HolidaySet::from(Frauentag)));
+        assert!((bayern & berlin).is_subset(&bayern));
+        assert!(!bayern.is_subset(&berlin));
+        let mut set = HolidaySet::EMPTY;
+        assert!(set.insert(Neujahr));
+        assert!(!set.insert(Neujahr));
+        assert!(set.remove(Neujahr));
+        assert!(set.is_empty());
+    }
+}
@@ -1,5 +1,9 @@
-use chrono::{Datelike, Duration, NaiveDate};
-use computus;
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use core::cell::OnceCell;
+
+use crate::calendar_date::CalendarDate;
+use crate::civil::{self, Ymd};
 
 /// All reoccurring holidays in Germany.
 /// This list contains both public and non-public holidays.
@@ -7,6 +11,19 @@ use computus;
 /// For public holidays use `GermanRegion` instead, since
 /// public holidays differ from region to region.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text, sql_type = diesel::sql_types::SmallInt)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Clone, Copy, Debug, Eq, PartialEq))
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum GermanHoliday {
     Neujahr,
     HeiligeDreiKoenige,
@@ -18,13 +35,21 @@ pub enum GermanHoliday {
     Ostersonntag,
     Ostermontag,
     ErsterMai,
+    /// Anniversary of the end of World War II in Europe.
+    TagDerBefreiung,
+    /// Soviet anniversary of the end of World War II, only a public holiday in the GDR in 1975.
+    TagDesSieges,
     ChristiHimmelfahrt,
     Pfingstsonntag,
     Pfingstmontag,
     Fronleichnam,
+    /// Tag der deutschen Einheit in West Germany from 1954 to 1990.
+    SiebzehnterJuni,
     AugsburgerFriedensfest,
     MariaeHimmelfahrt,
     Weltkindertag,
+    /// Founding day of the GDR.
+    TagDerRepublik,
     TagDerDeutschenEinheit,
     Reformationstag,
     Allerheiligen,
@@ -33,44 +58,161 @@ pub enum GermanHoliday {
     ErsterWeihnachtsfeiertag,
     ZweiterWeihnachtsfeiertag,
     Silvester,
+    /// Thursday before Aschermittwoch, a customary day off in the Rhineland but not a public holiday.
+    Weiberfastnacht,
+    /// Monday before Aschermittwoch, a customary day off in the Rhineland but not a public holiday.
+    Rosenmontag,
 }
 
 use GermanHoliday::*;
 
+/// Classification of a holiday within a region,
+/// compatible with the holiday types used by datasets like Nager.Date.
+///
+/// This crate currently only distinguishes `Public` and `Observance`,
+/// the other types exist for interoperability.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HolidayType {
+    /// Statutory public holiday.
+    Public,
+    /// Banks and offices are closed.
+    Bank,
+    /// Schools are closed.
+    School,
+    /// Employees may take the day off.
+    Optional,
+    /// Observed holiday without the day being free.
+    Observance,
+}
+
+/// Language of human-readable names and descriptions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    /// German, the language of the official names.
+    #[default]
+    German,
+    /// English.
+    English,
+}
+
+impl HolidayType {
+    /// Machine-readable identifier of the type, e.g. `"public"`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            HolidayType::Public => "public",
+            HolidayType::Bank => "bank",
+            HolidayType::School => "school",
+            HolidayType::Optional => "optional",
+            HolidayType::Observance => "observance",
+        }
+    }
+}
+
 impl GermanHoliday {
+    /// All holidays in calendar order, followed by holidays added later.
+    ///
+    /// New holidays are only ever appended, so indices into this list stay stable.
+    pub const ALL: &'static [GermanHoliday] = &[
+        Neujahr,
+        HeiligeDreiKoenige,
+        Frauentag,
+        Faschingsdienstag,
+        Aschermittwoch,
+        Gruendonnerstag,
+        Karfreitag,
+        Ostersonntag,
+        Ostermontag,
+        ErsterMai,
+        TagDerBefreiung,
+        TagDesSieges,
+        ChristiHimmelfahrt,
+        Pfingstsonntag,
+        Pfingstmontag,
+        Fronleichnam,
+        SiebzehnterJuni,
+        AugsburgerFriedensfest,
+        MariaeHimmelfahrt,
+        Weltkindertag,
+        TagDerRepublik,
+        TagDerDeutschenEinheit,
+        Reformationstag,
+        Allerheiligen,
+        BussUndBettag,
+        Heiligabend,
+        ErsterWeihnachtsfeiertag,
+        ZweiterWeihnachtsfeiertag,
+        Silvester,
+        Weiberfastnacht,
+        Rosenmontag,
+    ];
+
     /// Calculates the date for a specific year.
     ///
     /// `None` if it cannot be calculated.
+    #[cfg(feature = "chrono")]
     pub fn date(&self, year: i32) -> Option<NaiveDate> {
+        self.date_as(year)
+    }
+
+    /// Calculates the date for a specific year as any supported date type,
+    /// e.g. `time::Date` if the `time` feature is enabled.
+    ///
+    /// `None` if it cannot be calculated or represented.
+    pub fn date_as<D: CalendarDate>(&self, year: i32) -> Option<D> {
+        let (year, month, day) = self.ymd(year)?;
+        D::from_ymd(year, month, day)
+    }
+
+    /// Month and day of holidays which always fall on the same date,
+    /// e.g. `Some((10, 3))` for Tag der Deutschen Einheit.
+    ///
+    /// `None` for holidays depending on easter or the day of the week.
+    /// Can be used in const contexts, e.g. to build static tables.
+    pub const fn fixed_date(&self) -> Option<(u32, u32)> {
         match self {
-            Neujahr => date(year, 1, 1),
-            HeiligeDreiKoenige => date(year, 1, 6),
-            Frauentag => date(year, 3, 8),
-            Faschingsdienstag => relative_to_easter_sunday(year, -47),
-            Aschermittwoch => relative_to_easter_sunday(year, -46),
-            Gruendonnerstag => relative_to_easter_sunday(year, -3),
-            Karfreitag => relative_to_easter_sunday(year, -2),
-            Ostersonntag => relative_to_easter_sunday(year, 0),
-            Ostermontag => relative_to_easter_sunday(year, 1),
-            ErsterMai => date(year, 5, 1),
-            ChristiHimmelfahrt => relative_to_easter_sunday(year, 39),
-            Pfingstsonntag => relative_to_easter_sunday(year, 49),
-            Pfingstmontag => relative_to_easter_sunday(year, 50),
-            Fronleichnam => relative_to_easter_sunday(year, 60),
-            AugsburgerFriedensfest => date(year, 8, 8),
-            MariaeHimmelfahrt => date(year, 8, 15),
-            Weltkindertag => date(year, 9, 20),
-            TagDerDeutschenEinheit => date(year, 10, 3),
-            Reformationstag => date(year, 10, 31),
-            Allerheiligen => date(year, 11, 1),
-            BussUndBettag => bus_und_bettag(year),
-            Heiligabend => date(year, 12, 24),
-            ErsterWeihnachtsfeiertag => date(year, 12, 25),
-            ZweiterWeihnachtsfeiertag => date(year, 12, 26),
-            Silvester => date(year, 12, 31),
+            Neujahr => Some((1, 1)),
+            HeiligeDreiKoenige => Some((1, 6)),
+            Frauentag => Some((3, 8)),
+            ErsterMai => Some((5, 1)),
+            TagDerBefreiung => Some((5, 8)),
+            TagDesSieges => Some((5, 9)),
+            SiebzehnterJuni => Some((6, 17)),
+            AugsburgerFriedensfest => Some((8, 8)),
+            MariaeHimmelfahrt => Some((8, 15)),
+            Weltkindertag => Some((9, 20)),
+            TagDerRepublik => Some((10, 7)),
+            TagDerDeutschenEinheit => Some((10, 3)),
+            Reformationstag => Some((10, 31)),
+            Allerheiligen => Some((11, 1)),
+            Heiligabend => Some((12, 24)),
+            ErsterWeihnachtsfeiertag => Some((12, 25)),
+            ZweiterWeihnachtsfeiertag => Some((12, 26)),
+            Silvester => Some((12, 31)),
+            Faschingsdienstag | Aschermittwoch | Gruendonnerstag | Karfreitag | Ostersonntag
+            | Ostermontag | ChristiHimmelfahrt | Pfingstsonntag | Pfingstmontag | Fronleichnam
+            | BussUndBettag | Weiberfastnacht | Rosenmontag => None,
         }
     }
-    pub fn description(&self) -> &'static str {
+
+    pub(crate) fn ymd(&self, year: i32) -> Option<Ymd> {
+        HolidayYear::new(year).ymd(*self)
+    }
+
+    /// True for holidays of the GDR and of West Germany before reunification,
+    /// which are only observed in years in which they are a public holiday somewhere.
+    #[cfg(all(feature = "alloc", feature = "chrono"))]
+    pub(crate) const fn is_historical(&self) -> bool {
+        matches!(
+            self,
+            TagDerBefreiung | TagDesSieges | SiebzehnterJuni | TagDerRepublik
+        )
+    }
+
+    pub const fn description(&self) -> &'static str {
         match self {
             Neujahr => "Neujahr",
             HeiligeDreiKoenige => "Heilige Drei Könige",
@@ -82,13 +224,17 @@ impl GermanHoliday {
             Ostersonntag => "Ostersonntag",
             Ostermontag => "Ostermontag",
             ErsterMai => "Erster Mai",
+            TagDerBefreiung => "Tag der Befreiung",
+            TagDesSieges => "Tag des Sieges",
             ChristiHimmelfahrt => "Christi Himmelfahrt",
             Pfingstsonntag => "Pfingstsonntag",
             Pfingstmontag => "Pfingstmontag",
             Fronleichnam => "Fronleichnam",
+            SiebzehnterJuni => "Tag der deutschen Einheit (17. Juni)",
             AugsburgerFriedensfest => "Augsburger Friedensfest",
             MariaeHimmelfahrt => "Mariä Himmelfahrt",
             Weltkindertag => "Weltkindertag",
+            TagDerRepublik => "Tag der Republik",
             TagDerDeutschenEinheit => "Tag der Deutschen Einheit",
             Reformationstag => "Reformationstag",
             Allerheiligen => "Allerheiligen",
@@ -97,56 +243,364 @@ impl GermanHoliday {
             ErsterWeihnachtsfeiertag => "Erster Weihnachtsfeiertag",
             ZweiterWeihnachtsfeiertag => "Zweiter Weihnachtsfeiertag",
             Silvester => "Silvester",
+            Weiberfastnacht => "Weiberfastnacht",
+            Rosenmontag => "Rosenmontag",
+        }
+    }
+
+    /// Returns the name of the holiday in the given language.
+    pub const fn localized_name(&self, language: Language) -> &'static str {
+        match language {
+            Language::German => self.description(),
+            Language::English => self.english_name(),
+        }
+    }
+
+    /// Returns the English name of the holiday.
+    pub const fn english_name(&self) -> &'static str {
+        match self {
+            Neujahr => "New Year's Day",
+            HeiligeDreiKoenige => "Epiphany",
+            Frauentag => "International Women's Day",
+            Faschingsdienstag => "Shrove Tuesday",
+            Aschermittwoch => "Ash Wednesday",
+            Gruendonnerstag => "Maundy Thursday",
+            Karfreitag => "Good Friday",
+            Ostersonntag => "Easter Sunday",
+            Ostermontag => "Easter Monday",
+            ErsterMai => "Labour Day",
+            TagDerBefreiung => "Liberation Day",
+            TagDesSieges => "Victory Day",
+            ChristiHimmelfahrt => "Ascension Day",
+            Pfingstsonntag => "Whit Sunday",
+            Pfingstmontag => "Whit Monday",
+            Fronleichnam => "Corpus Christi",
+            SiebzehnterJuni => "Day of German Unity (17 June)",
+            AugsburgerFriedensfest => "Augsburg Peace Festival",
+            MariaeHimmelfahrt => "Assumption Day",
+            Weltkindertag => "World Children's Day",
+            TagDerRepublik => "Republic Day",
+            TagDerDeutschenEinheit => "German Unity Day",
+            Reformationstag => "Reformation Day",
+            Allerheiligen => "All Saints' Day",
+            BussUndBettag => "Repentance and Prayer Day",
+            Heiligabend => "Christmas Eve",
+            ErsterWeihnachtsfeiertag => "Christmas Day",
+            ZweiterWeihnachtsfeiertag => "St. Stephen's Day",
+            Silvester => "New Year's Eve",
+            Weiberfastnacht => "Women's Carnival Day",
+            Rosenmontag => "Rose Monday",
+        }
+    }
+
+    /// Returns a stable lowercase ASCII identifier of the holiday, e.g. `"tag-der-deutschen-einheit"`.
+    pub const fn slug(&self) -> &'static str {
+        match self {
+            Neujahr => "neujahr",
+            HeiligeDreiKoenige => "heilige-drei-koenige",
+            Frauentag => "frauentag",
+            Faschingsdienstag => "faschingsdienstag",
+            Aschermittwoch => "aschermittwoch",
+            Gruendonnerstag => "gruendonnerstag",
+            Karfreitag => "karfreitag",
+            Ostersonntag => "ostersonntag",
+            Ostermontag => "ostermontag",
+            ErsterMai => "erster-mai",
+            TagDerBefreiung => "tag-der-befreiung",
+            TagDesSieges => "tag-des-sieges",
+            ChristiHimmelfahrt => "christi-himmelfahrt",
+            Pfingstsonntag => "pfingstsonntag",
+            Pfingstmontag => "pfingstmontag",
+            Fronleichnam => "fronleichnam",
+            SiebzehnterJuni => "siebzehnter-juni",
+            AugsburgerFriedensfest => "augsburger-friedensfest",
+            MariaeHimmelfahrt => "mariae-himmelfahrt",
+            Weltkindertag => "weltkindertag",
+            TagDerRepublik => "tag-der-republik",
+            TagDerDeutschenEinheit => "tag-der-deutschen-einheit",
+            Reformationstag => "reformationstag",
+            Allerheiligen => "allerheiligen",
+            BussUndBettag => "buss-und-bettag",
+            Heiligabend => "heiligabend",
+            ErsterWeihnachtsfeiertag => "erster-weihnachtsfeiertag",
+            ZweiterWeihnachtsfeiertag => "zweiter-weihnachtsfeiertag",
+            Silvester => "silvester",
+            Weiberfastnacht => "weiberfastnacht",
+            Rosenmontag => "rosenmontag",
+        }
+    }
+
+    /// Returns the name of the variant, e.g. `"TagDerDeutschenEinheit"`,
+    /// which is also used by the serde implementations.
+    pub const fn identifier(&self) -> &'static str {
+        match self {
+            Neujahr => "Neujahr",
+            HeiligeDreiKoenige => "HeiligeDreiKoenige",
+            Frauentag => "Frauentag",
+            Faschingsdienstag => "Faschingsdienstag",
+            Aschermittwoch => "Aschermittwoch",
+            Gruendonnerstag => "Gruendonnerstag",
+            Karfreitag => "Karfreitag",
+            Ostersonntag => "Ostersonntag",
+            Ostermontag => "Ostermontag",
+            ErsterMai => "ErsterMai",
+            TagDerBefreiung => "TagDerBefreiung",
+            TagDesSieges => "TagDesSieges",
+            ChristiHimmelfahrt => "ChristiHimmelfahrt",
+            Pfingstsonntag => "Pfingstsonntag",
+            Pfingstmontag => "Pfingstmontag",
+            Fronleichnam => "Fronleichnam",
+            SiebzehnterJuni => "SiebzehnterJuni",
+            AugsburgerFriedensfest => "AugsburgerFriedensfest",
+            MariaeHimmelfahrt => "MariaeHimmelfahrt",
+            Weltkindertag => "Weltkindertag",
+            TagDerRepublik => "TagDerRepublik",
+            TagDerDeutschenEinheit => "TagDerDeutschenEinheit",
+            Reformationstag => "Reformationstag",
+            Allerheiligen => "Allerheiligen",
+            BussUndBettag => "BussUndBettag",
+            Heiligabend => "Heiligabend",
+            ErsterWeihnachtsfeiertag => "ErsterWeihnachtsfeiertag",
+            ZweiterWeihnachtsfeiertag => "ZweiterWeihnachtsfeiertag",
+            Silvester => "Silvester",
+            Weiberfastnacht => "Weiberfastnacht",
+            Rosenmontag => "Rosenmontag",
         }
     }
 }
 
-fn bus_und_bettag(year: i32) -> Option<NaiveDate> {
-    let reference_date = NaiveDate::from_ymd(year, 11, 23);
-    let weekday_ordinal = i64::from(reference_date.weekday().num_days_from_monday());
-    let duration_to_previous_wednesday = if weekday_ordinal < 3 {
-        Duration::days(-(weekday_ordinal + 5))
+#[cfg(feature = "tz")]
+impl GermanHoliday {
+    /// Calculates the start (inclusive) and end (exclusive) instants of the holiday in a specific year,
+    /// i.e. 00:00 to 24:00 in Europe/Berlin.
+    ///
+    /// `None` if it cannot be calculated.
+    pub fn instant_range(
+        &self,
+        year: i32,
+    ) -> Option<(
+        chrono::DateTime<chrono_tz::Tz>,
+        chrono::DateTime<chrono_tz::Tz>,
+    )> {
+        use chrono::TimeZone;
+        let date = self.date(year)?;
+        let midnight = |date: NaiveDate| {
+            chrono_tz::Europe::Berlin
+                .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+                .single()
+        };
+        Some((midnight(date)?, midnight(date.succ_opt()?)?))
+    }
+}
+
+fn bus_und_bettag(year: i32) -> Option<Ymd> {
+    let reference_date = (year, 11, 23);
+    let weekday_ordinal = civil::weekday_from_monday(reference_date);
+    let days_to_previous_wednesday = if weekday_ordinal < 3 {
+        -(weekday_ordinal + 5)
     } else {
-        Duration::days(2 - weekday_ordinal)
+        2 - weekday_ordinal
     };
-    Some(reference_date + duration_to_previous_wednesday)
+    civil::add_days(reference_date, days_to_previous_wednesday)
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<Ymd> {
+    Some((year, month, day))
+}
+
+/// Returns the dates of the given holidays in a year in chronological order, without allocating.
+///
+/// Holidays without a date in that year are skipped,
+/// holidays on the same date are ordered as in `GermanHoliday::ALL`.
+pub(crate) fn sorted_ymds(
+    holidays: impl Iterator<Item = GermanHoliday>,
+    year: i32,
+) -> impl DoubleEndedIterator<Item = (Ymd, GermanHoliday)> {
+    let mut holiday_dates = [None; GermanHoliday::ALL.len()];
+    let mut len = 0;
+    let holiday_year = HolidayYear::new(year);
+    for holiday in holidays {
+        if let Some(date) = holiday_year.ymd(holiday) {
+            holiday_dates[len] = Some((date, holiday));
+            len += 1;
+        }
+    }
+    // the variants are declared in the order of `GermanHoliday::ALL`
+    holiday_dates[..len].sort_unstable_by_key(|holiday_date| {
+        holiday_date.map(|(date, holiday)| (date, holiday as usize))
+    });
+    IntoIterator::into_iter(holiday_dates).flatten()
 }
 
-fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
-    NaiveDate::from_ymd_opt(year, month, day)
+/// Calculates the dates of holidays within a single year,
+/// computing easter sunday at most once for all easter-dependent holidays.
+pub(crate) struct HolidayYear {
+    year: i32,
+    easter_sunday: OnceCell<Option<Ymd>>,
 }
 
-fn relative_to_easter_sunday(year: i32, days_offset: i64) -> Option<NaiveDate> {
-    let easter_sunday = computus::gregorian(year).ok()?;
-    let date = NaiveDate::from_ymd_opt(easter_sunday.year, easter_sunday.month, easter_sunday.day)?;
-    Some(date + Duration::days(days_offset))
+impl HolidayYear {
+    pub(crate) fn new(year: i32) -> Self {
+        Self {
+            year,
+            easter_sunday: OnceCell::new(),
+        }
+    }
+
+    pub(crate) fn ymd(&self, holiday: GermanHoliday) -> Option<Ymd> {
+        let year = self.year;
+        match holiday {
+            Neujahr => date(year, 1, 1),
+            HeiligeDreiKoenige => date(year, 1, 6),
+            Frauentag => date(year, 3, 8),
+            Faschingsdienstag => self.relative_to_easter_sunday(-47),
+            Aschermittwoch => self.relative_to_easter_sunday(-46),
+            Gruendonnerstag => self.relative_to_easter_sunday(-3),
+            Karfreitag => self.relative_to_easter_sunday(-2),
+            Ostersonntag => self.relative_to_easter_sunday(0),
+            Ostermontag => self.relative_to_easter_sunday(1),
+            ErsterMai => date(year, 5, 1),
+            TagDerBefreiung => date(year, 5, 8),
+            TagDesSieges => date(year, 5, 9),
+            ChristiHimmelfahrt => self.relative_to_easter_sunday(39),
+            Pfingstsonntag => self.relative_to_easter_sunday(49),
+            Pfingstmontag => self.relative_to_easter_sunday(50),
+            Fronleichnam => self.relative_to_easter_sunday(60),
+            SiebzehnterJuni => date(year, 6, 17),
+            AugsburgerFriedensfest => date(year, 8, 8),
+            MariaeHimmelfahrt => date(year, 8, 15),
+            Weltkindertag => date(year, 9, 20),
+            TagDerRepublik => date(year, 10, 7),
+            TagDerDeutschenEinheit => date(year, 10, 3),
+            Reformationstag => date(year, 10, 31),
+            Allerheiligen => date(year, 11, 1),
+            BussUndBettag => bus_und_bettag(year),
+            Heiligabend => date(year, 12, 24),
+            ErsterWeihnachtsfeiertag => date(year, 12, 25),
+            ZweiterWeihnachtsfeiertag => date(year, 12, 26),
+            Silvester => date(year, 12, 31),
+            Weiberfastnacht => self.relative_to_easter_sunday(-52),
+            Rosenmontag => self.relative_to_easter_sunday(-48),
+        }
+    }
+
+    /// True if the holiday falls on the given month and day of this year.
+    ///
+    /// Fixed-date holidays are compared without computing any dates
+    /// and easter is only computed for dates on which an easter-dependent holiday can fall.
+    pub(crate) fn is_on(&self, holiday: GermanHoliday, (month, day): (u32, u32)) -> bool {
+        if let Some(fixed_date) = holiday.fixed_date() {
+            return fixed_date == (month, day);
+        }
+        // easter sunday falls between March 22nd and April 25th,
+        // so all easter-dependent holidays except Weiberfastnacht fall between February and June
+        let possible = match holiday {
+            BussUndBettag => month == 11,
+            Weiberfastnacht => (1..=3).contains(&month),
+            _ => (2..=6).contains(&month),
+        };
+        possible && self.ymd(holiday) == Some((self.year, month, day))
+    }
+
+    fn relative_to_easter_sunday(&self, days_offset: i64) -> Option<Ymd> {
+        let easter_sunday = *self
+            .easter_sunday
+            .get_or_init(|| civil::easter_sunday(self.year));
+        civil::add_days(easter_sunday?, days_offset)
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use super::*;
     use crate::DateExt;
-    use chrono::Weekday;
+    use chrono::{Datelike, Weekday};
     use proptest::prelude::*;
 
     proptest! {
     #[test]
     fn test_bus_und_bettag_is_wed_before_23th_nov(y in 1i32..2999) {
-        let date = bus_und_bettag(y).unwrap();
+        let (year, month, day) = bus_und_bettag(y).unwrap();
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
         assert_eq!(Weekday::Wed, date.weekday());
-        let duration = date.signed_duration_since(NaiveDate::from_ymd(y, 11, 23));
+        let duration = date.signed_duration_since(NaiveDate::from_ymd_opt(y, 11, 23).unwrap());
         assert!(duration.num_days() <= -1);
         assert!(duration.num_days() >= -7);
     }
     }
 
+    proptest! {
+    #[test]
+    fn is_on_agrees_with_ymd(year in 1583i32..3000, days in 0i64..366) {
+        let (_, month, day) = civil::add_days((year, 1, 1), days).unwrap();
+        let holiday_year = HolidayYear::new(year);
+        for &holiday in GermanHoliday::ALL {
+            assert_eq!(
+                holiday_year.is_on(holiday, (month, day)),
+                holiday.ymd(year) == Some((year, month, day))
+            );
+        }
+    }
+    }
+
     proptest! {
     #[test]
     fn relative_to_easter_sunday_does_not_panic(year: i32, offset: i64) {
-        relative_to_easter_sunday(year, offset);
+        HolidayYear::new(year).relative_to_easter_sunday(offset);
     }
     }
 
+    #[test]
+    fn fixed_date_matches_dates() {
+        const TAG_DER_DEUTSCHEN_EINHEIT: Option<(u32, u32)> = TagDerDeutschenEinheit.fixed_date();
+        assert_eq!(TAG_DER_DEUTSCHEN_EINHEIT, Some((10, 3)));
+
+        for holiday in GermanHoliday::ALL {
+            if let Some((month, day)) = holiday.fixed_date() {
+                for year in 2020..2030 {
+                    assert_eq!(holiday.ymd(year), Some((year, month, day)));
+                }
+            } else {
+                assert_ne!(
+                    holiday.ymd(2024).map(|(_, m, d)| (m, d)),
+                    holiday.ymd(2025).map(|(_, m, d)| (m, d))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn identifier_matches_debug() {
+        for holiday in GermanHoliday::ALL {
+            assert_eq!(holiday.identifier(), format!("{:?}", holiday));
+        }
+    }
+
+    #[test]
+    fn slugs_are_unique_lowercase_ascii() {
+        for (index, holiday) in GermanHoliday::ALL.iter().enumerate() {
+            assert!(holiday
+                .slug()
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b == b'-'));
+            assert!(GermanHoliday::ALL[..index]
+                .iter()
+                .all(|other| other.slug() != holiday.slug()));
+        }
+    }
+
+    #[cfg(feature = "tz")]
+    #[test]
+    fn instant_range_covers_berlin_day() {
+        use chrono::{TimeZone, Utc};
+        let (start, end) = Karfreitag.instant_range(2019).unwrap();
+        assert_eq!(Utc.with_ymd_and_hms(2019, 4, 18, 22, 0, 0).unwrap(), start);
+        assert_eq!(Utc.with_ymd_and_hms(2019, 4, 19, 22, 0, 0).unwrap(), end);
+        let (start, end) = Neujahr.instant_range(2020).unwrap();
+        assert_eq!(Utc.with_ymd_and_hms(2019, 12, 31, 23, 0, 0).unwrap(), start);
+        assert_eq!(Utc.with_ymd_and_hms(2020, 1, 1, 23, 0, 0).unwrap(), end);
+    }
+
     macro_rules! holiday_tests {
     ($($name:ident: $holiday:expr, $date:expr,)*) => {
     $(
@@ -154,7 +608,7 @@ mod tests {
         fn $name() {
             let holiday: GermanHoliday = $holiday;
             let (year, month, day) = $date;
-            let date = NaiveDate::from_ymd(year, month, day);
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
             assert!(date.is_holiday(holiday));
         }
     )*
@@ -178,13 +632,17 @@ mod tests {
 
         ostermontag: Ostermontag, (2019, 4, 22),
         erstermai: ErsterMai, (2019, 5, 1),
+        tag_der_befreiung: TagDerBefreiung, (2020, 5, 8),
+        tag_des_sieges: TagDesSieges, (1975, 5, 9),
         christi_himmelfahrt: ChristiHimmelfahrt, (2019, 5, 30),
         pfingstsonntag: Pfingstsonntag, (2019, 6, 9),
         pfingstmontag: Pfingstmontag, (2019, 6, 10),
         fronleichnam: Fronleichnam, (2019, 6, 20),
+        siebzehnter_juni: SiebzehnterJuni, (1989, 6, 17),
         augsburger_friedensfest: AugsburgerFriedensfest, (2019, 8, 8),
         mariae_himmelfahrt: MariaeHimmelfahrt, (2019, 8, 15),
         weltkindertag: Weltkindertag, (2019, 9, 20),
+        tag_der_republik: TagDerRepublik, (1989, 10, 7),
         deutsche_einheit: TagDerDeutschenEinheit, (2019, 10, 3),
         reformationstag: Reformationstag, (2019, 10, 31),
         allerheiligen: Allerheiligen, (2019, 11, 1),
@@ -200,6 +658,9 @@ mod tests {
         erster_weihnachtsfeiertag: ErsterWeihnachtsfeiertag, (2019, 12, 25),
         zweiter_weihnachtsfeiertag: ZweiterWeihnachtsfeiertag, (2019, 12, 26),
         silvester: Silvester, (2019, 12, 31),
+        weiberfastnacht1: Weiberfastnacht, (2019, 2, 28),
+        weiberfastnacht2: Weiberfastnacht, (2008, 1, 31),
+        rosenmontag1: Rosenmontag, (2019, 3, 4),
+        rosenmontag2: Rosenmontag, (2024, 2, 12),
     }
-
 }
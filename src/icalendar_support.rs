@@ -0,0 +1,290 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
+use chrono_tz::{Europe::Berlin, Tz};
+use core::ops::RangeInclusive;
+use icalendar::{Calendar, CalendarDateTime, Component, DatePerhapsTime, Event, EventLike};
+use thiserror::Error;
+
+use crate::ics::event_uid;
+use crate::regions::GermanRegion;
+
+impl GermanRegion {
+    /// Returns all public holidays within the given years as all-day `icalendar::Event`s,
+    /// with the same UIDs as the events in `to_ics`.
+    pub fn icalendar_events(&self, years: RangeInclusive<i32>) -> Vec<Event> {
+        years
+            .flat_map(|year| self.iter_holiday_dates(year))
+            .filter_map(|(date, holiday)| {
+                let end = date.succ_opt()?;
+                let timestamp = date.and_hms_opt(0, 0, 0)?.and_utc();
+                Some(
+                    Event::new()
+                        .uid(&event_uid(
+                            *self,
+                            holiday,
+                            (date.year(), date.month(), date.day()),
+                        ))
+                        .timestamp(timestamp)
+                        .starts(date)
+                        .ends(end)
+                        .summary(holiday.description())
+                        .done(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns all public holidays within the given years as an `icalendar::Calendar`,
+    /// see `icalendar_events`.
+    pub fn icalendar(&self, years: RangeInclusive<i32>) -> Calendar {
+        let mut calendar = Calendar::new();
+        for event in self.icalendar_events(years) {
+            calendar.push(event);
+        }
+        calendar.done()
+    }
+}
+
+/// Error returned when an iCalendar document cannot be imported, see `CompanyCalendar::add_ics`.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("failed to parse iCalendar document: {0}")]
+pub struct IcsImportError(String);
+
+/// A day or period on which a company is closed, e.g. Betriebsferien.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Closure {
+    /// First day of the closure.
+    pub start: NaiveDate,
+    /// Last day of the closure (inclusive).
+    pub end: NaiveDate,
+    /// Summary of the closure, if any.
+    pub summary: Option<String>,
+}
+
+/// The public holidays of a region combined with company-specific closures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompanyCalendar {
+    pub region: GermanRegion,
+    pub closures: Vec<Closure>,
+}
+
+impl CompanyCalendar {
+    /// Creates a calendar without any closures.
+    pub fn new(region: GermanRegion) -> Self {
+        Self {
+            region,
+            closures: Vec::new(),
+        }
+    }
+
+    /// Adds all events of an iCalendar document, e.g. an Outlook export, as closures.
+    ///
+    /// Events spanning multiple days close the company on each of these days.
+    /// Times in UTC or with a time zone known to the tz database are converted to German local time,
+    /// floating times and other time zones, e.g. the Windows names used by Outlook, are taken as German local time.
+    /// The end of an event is exclusive, so an event ending at midnight does not close the following day.
+    ///
+    /// Recurring events (`RRULE` or `RDATE`) are not supported and return an error,
+    /// in which case no closures are added.
+    pub fn add_ics(&mut self, ics: &str) -> Result<(), IcsImportError> {
+        let calendar = ics.parse::<Calendar>().map_err(IcsImportError)?;
+        let mut closures = Vec::new();
+        for event in calendar.iter().filter_map(|component| component.as_event()) {
+            if ["RRULE", "RDATE"].iter().any(|&key| {
+                event.properties().contains_key(key) || event.multi_properties().contains_key(key)
+            }) {
+                return Err(IcsImportError(format!(
+                    "recurring event {:?} is not supported",
+                    event
+                        .get_summary()
+                        .or_else(|| event.get_uid())
+                        .unwrap_or_default()
+                )));
+            }
+            let start_date = match event.get_start() {
+                Some(DatePerhapsTime::Date(start)) => start,
+                Some(DatePerhapsTime::DateTime(start)) => local_date_time(&start).date(),
+                None => continue,
+            };
+            let end = match event.get_end() {
+                Some(DatePerhapsTime::Date(end)) => end.pred_opt().unwrap_or(end),
+                Some(DatePerhapsTime::DateTime(end)) => {
+                    let end = local_date_time(&end);
+                    if end.time() == NaiveTime::MIN {
+                        end.date().pred_opt().unwrap_or(end.date())
+                    } else {
+                        end.date()
+                    }
+                }
+                None => start_date,
+            };
+            closures.push(Closure {
+                start: start_date,
+                end: end.max(start_date),
+                summary: event.get_summary().map(str::to_owned),
+            });
+        }
+        self.closures.extend(closures);
+        Ok(())
+    }
+
+    /// Returns the closure covering the given date, if any.
+    pub fn closure(&self, date: NaiveDate) -> Option<&Closure> {
+        self.closures
+            .iter()
+            .find(|closure| closure.start <= date && date <= closure.end)
+    }
+
+    /// True if the given date is a saturday, a sunday, a public holiday or a closure day.
+    pub fn is_free_day(&self, date: NaiveDate) -> bool {
+        matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+            || self.region.is_holiday(date)
+            || self.closure(date).is_some()
+    }
+}
+
+/// Converts an iCalendar date-time into German local time, see `CompanyCalendar::add_ics`.
+fn local_date_time(date_time: &CalendarDateTime) -> NaiveDateTime {
+    match date_time {
+        CalendarDateTime::Floating(date_time) => *date_time,
+        CalendarDateTime::Utc(date_time) => date_time.with_timezone(&Berlin).naive_local(),
+        CalendarDateTime::WithTimezone { date_time, tzid } => tzid
+            .parse::<Tz>()
+            .ok()
+            .and_then(|tz| tz.from_local_datetime(date_time).earliest())
+            .map_or(*date_time, |date_time| {
+                date_time.with_timezone(&Berlin).naive_local()
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+    use chrono::NaiveDate;
+    use icalendar::DatePerhapsTime;
+
+    #[test]
+    fn icalendar_events() {
+        let events = Hessen.icalendar_events(2024..=2024);
+        assert_eq!(events.len(), 10);
+        let fronleichnam = &events[6];
+        assert_eq!(fronleichnam.get_summary(), Some("Fronleichnam"));
+        assert_eq!(
+            fronleichnam.get_uid(),
+            Some("20240530-Fronleichnam-DE-HE@holiday_de")
+        );
+        assert_eq!(
+            fronleichnam.get_start(),
+            Some(DatePerhapsTime::Date(
+                NaiveDate::from_ymd_opt(2024, 5, 30).unwrap()
+            ))
+        );
+        assert_eq!(
+            fronleichnam.get_end(),
+            Some(DatePerhapsTime::Date(
+                NaiveDate::from_ymd_opt(2024, 5, 31).unwrap()
+            ))
+        );
+        assert_eq!(Hessen.icalendar(2024..=2025).components.len(), 20);
+    }
+
+    #[test]
+    fn company_calendar() {
+        let mut calendar = CompanyCalendar::new(Hessen);
+        calendar
+            .add_ics(
+                "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:test\r
+BEGIN:VEVENT\r
+UID:1\r
+DTSTART;VALUE=DATE:20241223\r
+DTEND;VALUE=DATE:20250102\r
+SUMMARY:Betriebsferien\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:2\r
+DTSTART:20240531T080000Z\r
+DTEND:20240531T170000Z\r
+SUMMARY:Brückentag\r
+END:VEVENT\r
+END:VCALENDAR\r
+",
+            )
+            .unwrap();
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert!(calendar.is_free_day(date(2024, 5, 30)));
+        assert!(calendar.is_free_day(date(2024, 5, 31)));
+        assert!(calendar.is_free_day(date(2024, 12, 23)));
+        assert!(calendar.is_free_day(date(2025, 1, 1)));
+        assert!(!calendar.is_free_day(date(2025, 1, 2)));
+        assert!(!calendar.is_free_day(date(2024, 12, 20)));
+        assert_eq!(
+            calendar
+                .closure(date(2024, 12, 27))
+                .unwrap()
+                .summary
+                .as_deref(),
+            Some("Betriebsferien")
+        );
+    }
+
+    fn company_calendar_from_event(event: &str) -> Result<CompanyCalendar, IcsImportError> {
+        let mut calendar = CompanyCalendar::new(Hessen);
+        calendar.add_ics(&format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:test\r\nBEGIN:VEVENT\r\nUID:1\r\n{}END:VEVENT\r\nEND:VCALENDAR\r\n",
+            event
+        ))?;
+        Ok(calendar)
+    }
+
+    #[test]
+    fn company_calendar_uses_german_local_time() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        // midnight in Berlin
+        let calendar =
+            company_calendar_from_event("DTSTART:20241222T230000Z\r\nDTEND:20241223T120000Z\r\n")
+                .unwrap();
+        assert_eq!(calendar.closures[0].start, date(2024, 12, 23));
+        assert_eq!(calendar.closures[0].end, date(2024, 12, 23));
+        assert!(!calendar.is_free_day(date(2024, 12, 20)));
+        let calendar = company_calendar_from_event(
+            "DTSTART;TZID=America/New_York:20240704T200000\r\nDTEND;TZID=America/New_York:20240704T210000\r\n",
+        )
+        .unwrap();
+        assert_eq!(calendar.closures[0].start, date(2024, 7, 5));
+    }
+
+    #[test]
+    fn company_calendar_end_at_midnight_is_exclusive() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let calendar =
+            company_calendar_from_event("DTSTART:20241223T080000\r\nDTEND:20241224T000000\r\n")
+                .unwrap();
+        assert_eq!(calendar.closures[0].end, date(2024, 12, 23));
+        assert!(!calendar.is_free_day(date(2024, 12, 24)));
+        // 2024-12-23T00:00 in Berlin
+        let calendar =
+            company_calendar_from_event("DTSTART:20241221T230000Z\r\nDTEND:20241222T230000Z\r\n")
+                .unwrap();
+        assert_eq!(calendar.closures[0].start, date(2024, 12, 22));
+        assert_eq!(calendar.closures[0].end, date(2024, 12, 22));
+    }
+
+    #[test]
+    fn company_calendar_rejects_recurring_events() {
+        let recurring = [
+            "DTSTART;VALUE=DATE:20241223\r\nRRULE:FREQ=YEARLY\r\nSUMMARY:Betriebsruhe\r\n",
+            "DTSTART;VALUE=DATE:20241223\r\nRDATE;VALUE=DATE:20251222\r\n",
+        ];
+        for event in recurring {
+            assert!(company_calendar_from_event(event).is_err());
+        }
+        let mut calendar = CompanyCalendar::new(Hessen);
+        assert!(calendar
+            .add_ics("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:1\r\nDTSTART;VALUE=DATE:20241223\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:2\r\nDTSTART;VALUE=DATE:20241223\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n")
+            .is_err());
+        assert!(calendar.closures.is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::civil::{self, Ymd};
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// A single event stored as its own resource in a CalDAV collection, see `GermanRegion::caldav_resources`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CalDavResource {
+    /// Name of the resource within the collection, derived from the UID of the event.
+    pub name: String,
+    /// The iCalendar document containing the event, to be uploaded with `PUT`.
+    pub ics: String,
+}
+
+impl GermanRegion {
+    /// Returns all public holidays within the given years as an iCalendar (RFC 5545) document
+    /// with one all-day event per holiday.
+    ///
+    /// The UID of each event only depends on region, holiday and date,
+    /// so re-importing an updated calendar replaces the existing events.
+    pub fn to_ics(&self, years: RangeInclusive<i32>) -> String {
+        let mut ics = String::new();
+        push_calendar_start(&mut ics);
+        for year in years {
+            for (date, holiday) in self.iter_holiday_ymds(year) {
+                push_event(&mut ics, *self, holiday, date);
+            }
+        }
+        push_line(&mut ics, "END:VCALENDAR");
+        ics
+    }
+
+    /// Returns all public holidays within the given years as one resource per event,
+    /// for publishing to a CalDAV collection (RFC 4791).
+    ///
+    /// Resource names are stable, so uploading each resource with `PUT`
+    /// updates existing events instead of creating duplicates.
+    /// Sending the requests is left to the caller.
+    pub fn caldav_resources(&self, years: RangeInclusive<i32>) -> Vec<CalDavResource> {
+        years
+            .flat_map(|year| self.iter_holiday_ymds(year))
+            .map(|(date, holiday)| {
+                let mut ics = String::new();
+                push_calendar_start(&mut ics);
+                push_event(&mut ics, *self, holiday, date);
+                push_line(&mut ics, "END:VCALENDAR");
+                CalDavResource {
+                    name: format!("{}.ics", event_uid(*self, holiday, date)),
+                    ics,
+                }
+            })
+            .collect()
+    }
+}
+
+fn push_calendar_start(ics: &mut String) {
+    push_line(ics, "BEGIN:VCALENDAR");
+    push_line(ics, "VERSION:2.0");
+    push_line(ics, "PRODID:-//holiday_de//Feiertage//DE");
+    push_line(ics, "CALSCALE:GREGORIAN");
+}
+
+fn push_event(ics: &mut String, region: GermanRegion, holiday: GermanHoliday, date: Ymd) {
+    let end = match civil::add_days(date, 1) {
+        Some(end) => end,
+        None => return,
+    };
+    push_line(ics, "BEGIN:VEVENT");
+    push_line(ics, &format!("UID:{}", event_uid(region, holiday, date)));
+    push_line(ics, &format!("DTSTAMP:{}T000000Z", format_date(date)));
+    push_line(ics, &format!("DTSTART;VALUE=DATE:{}", format_date(date)));
+    push_line(ics, &format!("DTEND;VALUE=DATE:{}", format_date(end)));
+    push_line(
+        ics,
+        &format!("SUMMARY:{}", escape_text(holiday.description())),
+    );
+    push_line(ics, "TRANSP:TRANSPARENT");
+    push_line(ics, "END:VEVENT");
+}
+
+impl GermanHoliday {
+    /// Returns the recurrence of the holiday as an iCalendar (RFC 5545) property.
+    ///
+    /// Holidays with a fixed date and Buß- und Bettag are described by an `RRULE`.
+    /// For holidays relative to easter, an `RDATE` listing the dates within the given years is returned,
+    /// or `None` if there are no such dates, e.g. for an empty range of years.
+    pub fn rrule(&self, years: RangeInclusive<i32>) -> Option<String> {
+        if let Some((month, day)) = self.fixed_date() {
+            return Some(format!(
+                "RRULE:FREQ=YEARLY;BYMONTH={};BYMONTHDAY={}",
+                month, day
+            ));
+        }
+        if *self == GermanHoliday::BussUndBettag {
+            // the wednesday before the 23rd of November
+            return Some(
+                "RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=WE;BYMONTHDAY=16,17,18,19,20,21,22".to_owned(),
+            );
+        }
+        let dates = years
+            .filter_map(|year| self.ymd(year))
+            .map(format_date)
+            .collect::<Vec<_>>();
+        if dates.is_empty() {
+            return None;
+        }
+        Some(format!("RDATE;VALUE=DATE:{}", dates.join(",")))
+    }
+}
+
+/// Stable UID of the event for a holiday in a region.
+pub(crate) fn event_uid(region: GermanRegion, holiday: GermanHoliday, date: Ymd) -> String {
+    format!(
+        "{}-{:?}-{}@holiday_de",
+        format_date(date),
+        holiday,
+        region.iso_code()
+    )
+}
+
+fn format_date((year, month, day): Ymd) -> String {
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// Escapes a TEXT value as described in RFC 5545, section 3.3.11.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends a content line, folded after at most 75 octets as described in RFC 5545, section 3.1.
+fn push_line(ics: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > 75 {
+            ics.push_str("\r\n ");
+            // the leading space counts towards the length of the continuation line
+            octets = 1;
+        }
+        ics.push(c);
+        octets += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn to_ics() {
+        let ics = Bayern.to_ics(2024..=2025);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 26);
+        assert!(ics.contains(
+            "BEGIN:VEVENT\r\nUID:20241101-Allerheiligen-DE-BY@holiday_de\r\nDTSTAMP:20241101T000000Z\r\nDTSTART;VALUE=DATE:20241101\r\nDTEND;VALUE=DATE:20241102\r\nSUMMARY:Allerheiligen\r\n"
+        ));
+        assert!(ics.contains("DTEND;VALUE=DATE:20251227"));
+    }
+
+    #[test]
+    fn caldav_resources() {
+        let resources = Berlin.caldav_resources(2025..=2025);
+        assert_eq!(resources.len(), 11);
+        let befreiung = resources
+            .iter()
+            .find(|resource| resource.name == "20250508-TagDerBefreiung-DE-BE@holiday_de.ics")
+            .unwrap();
+        assert!(befreiung.ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(befreiung.ics.matches("BEGIN:VEVENT").count(), 1);
+        assert!(befreiung.ics.contains("SUMMARY:Tag der Befreiung\r\n"));
+    }
+
+    #[test]
+    fn rrule() {
+        assert_eq!(
+            GermanHoliday::Allerheiligen.rrule(2024..=2025).as_deref(),
+            Some("RRULE:FREQ=YEARLY;BYMONTH=11;BYMONTHDAY=1")
+        );
+        assert_eq!(
+            GermanHoliday::Ostermontag.rrule(2024..=2026).as_deref(),
+            Some("RDATE;VALUE=DATE:20240401,20250421,20260406")
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 2026..=2024;
+        assert_eq!(GermanHoliday::Ostermontag.rrule(empty), None);
+        assert!(GermanHoliday::BussUndBettag
+            .rrule(2024..=2024)
+            .unwrap()
+            .starts_with("RRULE:"));
+    }
+
+    #[test]
+    fn escape_text() {
+        assert_eq!(super::escape_text("a,b;c\\d\ne"), r"a\,b\;c\\d\ne");
+    }
+
+    #[test]
+    fn fold_long_lines() {
+        let mut ics = String::new();
+        push_line(&mut ics, &"ä".repeat(50));
+        let lines = ics.split("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= 75));
+        assert_eq!(lines[0].len(), 74);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[2], "");
+    }
+}
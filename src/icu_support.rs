@@ -0,0 +1,43 @@
+use core::convert::TryFrom;
+use icu_calendar::{Date, Iso};
+
+use crate::calendar_date::CalendarDate;
+
+/// Dates of other calendars can be converted via `to_calendar(Iso)`.
+impl CalendarDate for Date<Iso> {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        Date::try_new_iso(year, u8::try_from(month).ok()?, u8::try_from(day).ok()?).ok()
+    }
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        (
+            self.year().extended_year(),
+            u32::from(self.month().ordinal),
+            u32::from(self.day_of_month().0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+    use crate::DateExt;
+    use icu_calendar::cal::Gregorian;
+
+    #[test]
+    fn icu_date_is_holiday() {
+        let date = Date::try_new_iso(2019, 10, 3).unwrap();
+        assert!(date.is_public_holiday_in(Berlin));
+        assert_eq!(Some(TagDerDeutschenEinheit), date.public_holiday_in(Berlin));
+        let gregorian = date.to_calendar(Gregorian);
+        assert!(gregorian
+            .to_calendar(Iso)
+            .is_holiday(TagDerDeutschenEinheit));
+        assert_eq!(Some(date), TagDerDeutschenEinheit.date_as(2019));
+        assert_eq!(
+            Berlin.holidays_in_year(2019).len(),
+            Berlin.holiday_dates_in_year_as::<Date<Iso>>(2019).len()
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use core::convert::TryFrom;
+
+use crate::calendar_date::CalendarDate;
+
+impl CalendarDate for jiff::civil::Date {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        jiff::civil::Date::new(
+            i16::try_from(year).ok()?,
+            i8::try_from(month).ok()?,
+            i8::try_from(day).ok()?,
+        )
+        .ok()
+    }
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        (
+            i32::from(self.year()),
+            self.month() as u32,
+            self.day() as u32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+    use crate::DateExt;
+    use jiff::civil::Date;
+
+    #[test]
+    fn jiff_date_is_holiday() {
+        let date = Date::new(2019, 10, 3).unwrap();
+        assert!(date.is_public_holiday_in(Berlin));
+        assert_eq!(Some(TagDerDeutschenEinheit), date.public_holiday_in(Berlin));
+        assert!(date.is_holiday(TagDerDeutschenEinheit));
+        assert_eq!(Some(date), TagDerDeutschenEinheit.date_as(2019));
+        assert_eq!(
+            Berlin.iter_holidays_in_year(2019).count(),
+            Berlin.iter_holiday_dates_as::<Date>(2019).count()
+        );
+    }
+}
@@ -1,44 +1,175 @@
 //! Small crate to calculate public holidays for each German federal state.
 //!
-//! This crate can calculate all *reoccurring* German holidays, which exist since 1995.
+//! This crate can calculate all *reoccurring* German holidays since 1990.
 //!
 //! There are some weird edge cases depending on the respective region,
 //! see `GermanRegion` for more details.
 //! A comprehensive overview can be found within the German Wikipedia
 //! [Gesetzliche Feiertage in Deutschland](https://de.wikipedia.org/wiki/Gesetzliche_Feiertage_in_Deutschland).
-use chrono::{Datelike, NaiveDate};
+//!
+//! Without the default `std` feature, the crate is `no_std`.
+//! APIs returning collections additionally require the `alloc` feature,
+//! their iterator and array counterparts work without an allocator.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "arrow")]
+mod arrow_support;
+mod augsburg;
+mod bundesland;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod calendar;
+mod calendar_date;
+mod civil;
+#[cfg(feature = "chrono")]
+mod clock;
+#[cfg(feature = "alloc")]
+mod csv;
+#[cfg(feature = "diesel")]
+mod diesel_support;
+mod easter;
+mod error;
+#[cfg(feature = "json")]
+mod export;
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+mod feed;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "historical")]
+mod historical;
+mod holiday_set;
 mod holidays;
+#[cfg(feature = "icalendar")]
+mod icalendar_support;
+#[cfg(feature = "alloc")]
+mod ics;
+#[cfg(feature = "icu_calendar")]
+mod icu_support;
+#[cfg(feature = "jiff")]
+mod jiff_support;
+#[cfg(feature = "alloc")]
+mod markdown;
+#[cfg(feature = "alloc")]
+mod month_grid;
+mod multi_region;
+mod one_off;
+#[cfg(feature = "pdf")]
+mod pdf;
+#[cfg(feature = "rayon")]
+mod rayon_support;
+#[cfg(feature = "serde")]
+pub mod region_serde;
+mod region_year;
 mod regions;
+mod rules;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "sqlx")]
+mod sqlx_support;
+#[cfg(feature = "test-strategies")]
+pub mod strategies;
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+mod table;
+#[cfg(feature = "time")]
+mod time_support;
+#[cfg(feature = "alloc")]
+mod validation;
+#[cfg(feature = "alloc")]
+mod year_calendar;
+
+pub use augsburg::{is_augsburg_ags, is_augsburg_plz};
+pub use bundesland::{municipal_holidays, Bundesland};
+#[cfg(feature = "std")]
+pub use cache::HolidayCache;
+#[cfg(feature = "alloc")]
+pub use calendar::HolidayCalendar;
+pub use calendar_date::CalendarDate;
+#[cfg(feature = "clock")]
+pub use clock::SystemClock;
+#[cfg(feature = "chrono")]
+pub use clock::{Clock, FixedClock};
+#[cfg(feature = "alloc")]
+pub use csv::to_csv;
+pub use error::{HolidayError, NotABundeslandError, ParseRegionError};
+#[cfg(feature = "json")]
+pub use export::{feiertage_api_json, nager_date_json};
+#[cfg(feature = "historical")]
+pub use historical::Ddr;
+pub use holiday_set::HolidaySet;
+pub use holidays::{GermanHoliday, HolidayType, Language};
+#[cfg(feature = "icalendar")]
+pub use icalendar_support::{Closure, CompanyCalendar, IcsImportError};
+#[cfg(feature = "alloc")]
+pub use ics::CalDavResource;
+#[cfg(feature = "alloc")]
+pub use markdown::{markdown_matrix, markdown_table};
+#[cfg(feature = "alloc")]
+pub use multi_region::regions_with_holiday_on;
+pub use multi_region::{
+    common_holidays, holiday_anywhere_in_germany, is_holiday_anywhere_in_germany,
+    iter_regions_with_holiday_on, RegionDiff,
+};
+pub use one_off::{OneOffHoliday, ONE_OFF_HOLIDAYS};
+#[cfg(all(feature = "rayon", feature = "chrono"))]
+pub use rayon_support::par_holiday_table;
+#[cfg(feature = "rayon")]
+pub use rayon_support::par_to_csv;
+pub use region_year::RegionYear;
+#[cfg(feature = "chrono")]
+pub use regions::DatedHoliday;
+pub use regions::{supported_year_range, GermanRegion, MAX_HOLIDAYS_PER_YEAR};
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+pub use regions::{DayDescription, FreeDays};
+#[cfg(feature = "alloc")]
+pub use rules::legal_changes;
+pub use rules::{data_version, ChangeKind, HolidayRule, LegalChange, HOLIDAY_RULES};
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+pub use table::{holiday_table, HolidayTableRow};
+#[cfg(feature = "alloc")]
+pub use validation::Discrepancy;
+#[cfg(all(feature = "alloc", feature = "rkyv"))]
+pub use year_calendar::{ArchivedYearCalendar, ArchivedYearCalendarEntry};
+#[cfg(feature = "alloc")]
+pub use year_calendar::{YearCalendar, YearCalendarEntry};
 
-pub use holidays::GermanHoliday;
-pub use regions::GermanRegion;
+/// Re-export of the `chrono` version used in the public API.
+///
+/// Use `holiday_de::chrono::NaiveDate` to avoid type mismatches
+/// if your crate depends on a different version of `chrono`.
+#[cfg(feature = "chrono")]
+pub use chrono;
 
 /// Provides convenience methods for datelike data structures like `NaiveDate`.
+///
+/// Implemented for all types implementing `CalendarDate`.
 pub trait DateExt {
     /// True if date is a holiday within the specified region.
     ///
-    /// Always `false` for dates before 1995.
+    /// Always `false` for dates before 1990.
     fn is_public_holiday_in(&self, region: GermanRegion) -> bool;
 
     /// Returns the holiday if given date is a public holiday.
     ///
-    /// Always `None` for dates before 1995.
+    /// Always `None` for dates before 1990.
     fn public_holiday_in(&self, region: GermanRegion) -> Option<GermanHoliday>;
 
     /// True if date falls on the date of the given holiday.
     fn is_holiday(&self, holiday: GermanHoliday) -> bool;
 }
 
-impl DateExt for NaiveDate {
+impl<D: CalendarDate> DateExt for D {
     fn is_public_holiday_in(&self, region: GermanRegion) -> bool {
-        region.is_holiday(*self)
+        self.public_holiday_in(region).is_some()
     }
     fn public_holiday_in(&self, region: GermanRegion) -> Option<GermanHoliday> {
-        region.holiday_from_date(*self)
+        region.holiday_from_ymd(self.to_ymd())
     }
     fn is_holiday(&self, holiday: GermanHoliday) -> bool {
-        let holiday_date = holiday.date(self.year());
-        Some(*self) == holiday_date
+        let (year, month, day) = self.to_ymd();
+        holidays::HolidayYear::new(year).is_on(holiday, (month, day))
     }
 }
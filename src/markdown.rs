@@ -0,0 +1,88 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::civil::Ymd;
+use crate::regions::{holiday_ymds_of_regions, GermanRegion};
+
+/// Returns the public holidays of the given regions in the given year as a Markdown table
+/// with the columns Datum, Feiertag and Regionen, sorted by date.
+pub fn markdown_table(regions: &[GermanRegion], year: i32) -> String {
+    let mut markdown = String::from("| Datum | Feiertag | Regionen |\n|---|---|---|\n");
+    for (date, holiday, holiday_regions) in holiday_ymds_of_regions(regions, year) {
+        let codes = holiday_regions
+            .iter()
+            .map(|region| region.short_code())
+            .collect::<Vec<_>>();
+        markdown.push_str(&format!(
+            "| {} | {} | {} |\n",
+            format_date(date),
+            holiday.description(),
+            codes.join(", ")
+        ));
+    }
+    markdown
+}
+
+/// Returns the public holidays of the given regions in the given year as a Markdown table
+/// with one row per holiday and one column per region, marking the regions in which it applies.
+pub fn markdown_matrix(regions: &[GermanRegion], year: i32) -> String {
+    let mut markdown = String::from("| Datum | Feiertag |");
+    for &region in regions {
+        markdown.push_str(&format!(" {} |", region.short_code()));
+    }
+    markdown.push_str("\n|---|---|");
+    for _ in regions {
+        markdown.push_str(":-:|");
+    }
+    markdown.push('\n');
+    for (date, holiday, holiday_regions) in holiday_ymds_of_regions(regions, year) {
+        markdown.push_str(&format!(
+            "| {} | {} |",
+            format_date(date),
+            holiday.description()
+        ));
+        for region in regions {
+            markdown.push_str(if holiday_regions.contains(region) {
+                " ✓ |"
+            } else {
+                "  |"
+            });
+        }
+        markdown.push('\n');
+    }
+    markdown
+}
+
+fn format_date((year, month, day): Ymd) -> String {
+    format!("{:02}.{:02}.{:04}", day, month, year)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn markdown_table() {
+        let markdown = super::markdown_table(&[Berlin, Brandenburg], 2024);
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "| Datum | Feiertag | Regionen |");
+        assert_eq!(lines[1], "|---|---|---|");
+        assert_eq!(lines[2], "| 01.01.2024 | Neujahr | BE, BB |");
+        assert_eq!(lines[3], "| 08.03.2024 | Frauentag | BE |");
+        assert_eq!(lines.len(), 2 + 11);
+    }
+
+    #[test]
+    fn markdown_matrix() {
+        let markdown = super::markdown_matrix(&[Berlin, Brandenburg], 2024);
+        let lines = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "| Datum | Feiertag | BE | BB |");
+        assert_eq!(lines[1], "|---|---|:-:|:-:|");
+        assert_eq!(lines[3], "| 08.03.2024 | Frauentag | ✓ |  |");
+        assert_eq!(
+            lines.last(),
+            Some(&"| 26.12.2024 | Zweiter Weihnachtsfeiertag | ✓ | ✓ |")
+        );
+    }
+}
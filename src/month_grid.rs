@@ -0,0 +1,117 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::civil;
+use crate::regions::GermanRegion;
+
+/// German names of the months.
+pub(crate) const MONTHS: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+/// Width of a rendered week in characters.
+const WIDTH: usize = 21;
+
+impl GermanRegion {
+    /// Renders a month as a text grid similar to `cal`, with weeks starting on monday.
+    ///
+    /// Public holidays are marked with `*` and listed in a legend below the grid.
+    /// `None` if the month is not between 1 and 12.
+    pub fn month_grid(&self, year: i32, month: u32) -> Option<String> {
+        let month_name = MONTHS.get(month.checked_sub(1)? as usize)?;
+        let holidays = self
+            .iter_holiday_ymds(year)
+            .filter(|&((_, holiday_month, _), _)| holiday_month == month)
+            .collect::<Vec<_>>();
+        let title = format!("{} {}", month_name, year);
+        // count characters instead of bytes so umlauts do not shift the title
+        let padding = WIDTH.saturating_sub(title.chars().count()) / 2;
+        let mut grid = format!("{}{}\n", " ".repeat(padding), title);
+        grid.push_str(" Mo Di Mi Do Fr Sa So\n");
+        let first_weekday = civil::weekday_from_monday((year, month, 1)) as usize;
+        grid.push_str(&"   ".repeat(first_weekday));
+        for day in 1..=civil::days_in_month(year, month) {
+            let marker = if holidays
+                .iter()
+                .any(|&((_, _, holiday_day), _)| holiday_day == day)
+            {
+                '*'
+            } else {
+                ' '
+            };
+            grid.push_str(&format!("{}{:>2}", marker, day));
+            if (first_weekday + day as usize).is_multiple_of(7) {
+                grid.push('\n');
+            }
+        }
+        if !grid.ends_with('\n') {
+            grid.push('\n');
+        }
+        if !holidays.is_empty() {
+            grid.push('\n');
+            for ((_, _, day), holiday) in holidays {
+                grid.push_str(&format!("*{:>2} {}\n", day, holiday.description()));
+            }
+        }
+        Some(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn month_grid() {
+        assert_eq!(
+            Berlin
+                .month_grid(2024, 3)
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>(),
+            [
+                "      März 2024",
+                " Mo Di Mi Do Fr Sa So",
+                "              1  2  3",
+                "  4  5  6  7* 8  9 10",
+                " 11 12 13 14 15 16 17",
+                " 18 19 20 21 22 23 24",
+                " 25 26 27 28*29 30 31",
+                "",
+                "* 8 Frauentag",
+                "*29 Karfreitag",
+            ]
+        );
+        assert_eq!(
+            Bayern
+                .month_grid(2024, 9)
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>(),
+            [
+                "   September 2024",
+                " Mo Di Mi Do Fr Sa So",
+                "                    1",
+                "  2  3  4  5  6  7  8",
+                "  9 10 11 12 13 14 15",
+                " 16 17 18 19 20 21 22",
+                " 23 24 25 26 27 28 29",
+                " 30",
+            ]
+        );
+        assert_eq!(Bayern.month_grid(2024, 13), None);
+        assert_eq!(Bayern.month_grid(2024, 0), None);
+    }
+}
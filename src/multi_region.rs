@@ -0,0 +1,167 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::calendar_date::CalendarDate;
+use crate::holiday_set::HolidaySet;
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// Returns the public holidays in the given year which apply in all of the given regions.
+///
+/// Use `HolidaySet::iter_dates` to get their dates. Empty if no regions are given.
+pub fn common_holidays(regions: &[GermanRegion], year: i32) -> HolidaySet {
+    match regions.split_first() {
+        Some((first, rest)) => rest.iter().fold(first.holiday_set(year), |common, region| {
+            common & region.holiday_set(year)
+        }),
+        None => HolidaySet::EMPTY,
+    }
+}
+
+/// The differences between the public holidays of two regions in a year, see `GermanRegion::diff`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RegionDiff {
+    /// Holidays which only apply in the first region.
+    pub only_self: HolidaySet,
+    /// Holidays which only apply in the second region.
+    pub only_other: HolidaySet,
+    /// Holidays which apply in both regions.
+    pub shared: HolidaySet,
+}
+
+impl GermanRegion {
+    /// Compares the public holidays of this region with those of another region in the given year,
+    /// e.g. to find out what changes when moving from one region to the other.
+    pub fn diff(&self, other: GermanRegion, year: i32) -> RegionDiff {
+        let own = self.holiday_set(year);
+        let other = other.holiday_set(year);
+        RegionDiff {
+            only_self: own - other,
+            only_other: other - own,
+            shared: own & other,
+        }
+    }
+}
+
+/// True if the given date is a public holiday in at least one German region.
+pub fn is_holiday_anywhere_in_germany<D: CalendarDate>(date: &D) -> bool {
+    holiday_anywhere_in_germany(date).is_some()
+}
+
+/// Returns the public holiday on the given date in the first region of `GermanRegion::ALL`
+/// in which the date is a public holiday, if any.
+pub fn holiday_anywhere_in_germany<D: CalendarDate>(date: &D) -> Option<GermanHoliday> {
+    let date = date.to_ymd();
+    GermanRegion::ALL
+        .iter()
+        .find_map(|region| region.holiday_from_ymd(date))
+}
+
+/// Returns all regions in which the given date is a public holiday, together with the holiday.
+///
+/// Use `iter_regions_with_holiday_on` to avoid allocating.
+#[cfg(feature = "alloc")]
+pub fn regions_with_holiday_on<D: CalendarDate>(date: &D) -> Vec<(GermanRegion, GermanHoliday)> {
+    iter_regions_with_holiday_on(date).collect()
+}
+
+/// Returns an iterator over all regions in which the given date is a public holiday,
+/// together with the holiday.
+///
+/// Same as `regions_with_holiday_on`, but without allocating.
+pub fn iter_regions_with_holiday_on<D: CalendarDate>(
+    date: &D,
+) -> impl Iterator<Item = (GermanRegion, GermanHoliday)> {
+    let date = date.to_ymd();
+    GermanRegion::ALL
+        .iter()
+        .filter_map(move |&region| Some((region, region.holiday_from_ymd(date)?)))
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::supported_year_range;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn bundesweit_matches_common_holidays() {
+        for year in supported_year_range() {
+            assert_eq!(
+                Bundesweit.holiday_set(year),
+                common_holidays(GermanRegion::ALL, year),
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn common_holidays_of_regions() {
+        assert_eq!(common_holidays(&[], 2024), HolidaySet::EMPTY);
+        assert_eq!(common_holidays(&[Bayern], 2024), Bayern.holiday_set(2024));
+        let common = common_holidays(&[Bayern, Saarland, NordrheinWestfalen], 2024);
+        assert_eq!(common.len(), 11);
+        assert!(common.contains(Fronleichnam));
+        assert!(!common.contains(MariaeHimmelfahrt));
+        let common = common_holidays(&[Bayern, Berlin], 2024);
+        assert_eq!(
+            common.iter_dates(2024).next_back(),
+            Some((
+                NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+                ZweiterWeihnachtsfeiertag
+            ))
+        );
+        assert!(common.iter_dates(2024).eq(Berlin
+            .iter_holiday_dates(2024)
+            .filter(|&(_, holiday)| holiday != Frauentag)));
+    }
+
+    #[test]
+    fn holiday_anywhere_in_germany() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        assert_eq!(
+            super::holiday_anywhere_in_germany(&date(3, 8)),
+            Some(Frauentag)
+        );
+        assert_eq!(super::holiday_anywhere_in_germany(&date(8, 8)), None);
+        assert_eq!(
+            super::holiday_anywhere_in_germany(&date(11, 20)),
+            Some(BussUndBettag)
+        );
+        assert!(is_holiday_anywhere_in_germany(&date(1, 1)));
+        assert!(!is_holiday_anywhere_in_germany(&date(1, 2)));
+    }
+
+    #[test]
+    fn regions_with_holiday_on() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        assert_eq!(
+            super::regions_with_holiday_on(&date(3, 8)),
+            [(Berlin, Frauentag), (MechlenburgVorpommern, Frauentag)]
+        );
+        assert_eq!(
+            super::regions_with_holiday_on(&date(1, 1)).len(),
+            GermanRegion::ALL.len()
+        );
+        assert_eq!(iter_regions_with_holiday_on(&date(1, 2)).next(), None);
+    }
+
+    #[test]
+    fn diff() {
+        let diff = NordrheinWestfalen.diff(Bayern, 2024);
+        assert_eq!(diff.only_self, HolidaySet::EMPTY);
+        assert_eq!(
+            diff.only_other.iter().collect::<Vec<_>>(),
+            [HeiligeDreiKoenige, MariaeHimmelfahrt]
+        );
+        assert_eq!(diff.shared, NordrheinWestfalen.holiday_set(2024));
+        let diff = Berlin.diff(Brandenburg, 2024);
+        assert_eq!(diff.only_self, HolidaySet::from(Frauentag));
+        assert_eq!(diff.only_other, HolidaySet::from(Reformationstag));
+        assert_eq!(diff.shared.len(), 9);
+    }
+}
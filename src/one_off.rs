@@ -0,0 +1,101 @@
+use crate::holidays::GermanHoliday;
+use crate::holidays::GermanHoliday::*;
+use crate::regions::GermanRegion;
+use crate::regions::GermanRegion::*;
+
+/// A public holiday which only applied once in a single region.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OneOffHoliday {
+    pub year: i32,
+    pub region: GermanRegion,
+    pub holiday: GermanHoliday,
+    /// Legal source of the holiday.
+    pub source: &'static str,
+}
+
+/// All one-off public holidays, which are included in `GermanRegion::holidays_in_year`.
+pub static ONE_OFF_HOLIDAYS: &[OneOffHoliday] = &[
+    OneOffHoliday {
+        year: 2017,
+        region: BadenWuerttemberg,
+        holiday: Reformationstag,
+        source: "https://www.landesrecht-bw.de/perma?d=jlr-FeiertGBWV1P1a",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: Bayern,
+        holiday: Reformationstag,
+        source: "https://www.bayern.landtag.de/www/ElanTextAblage_WP17/Drucksachen/Folgedrucksachen/0000007000/0000007463.pdf",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: Berlin,
+        holiday: Reformationstag,
+        source: "https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020151025/part/x",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: Hessen,
+        holiday: Reformationstag,
+        source: "https://www.rv.hessenrecht.hessen.de/bshe/document/jlr-RefT2017VHErahmen/part/X",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: NordrheinWestfalen,
+        holiday: Reformationstag,
+        source: "https://www.landtag.nrw.de/portal/WWW/dokumentenarchiv/Dokument?Id=XMMGVB1528%7C496%7C496",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: RheinlandPfalz,
+        holiday: Reformationstag,
+        source: "https://web.archive.org/web/20160305005630/https://www.rlp.de/fr/aktuelles/einzelansicht/news/detail/News/zusaetzlicher-feiertag-2017/",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: Saarland,
+        holiday: Reformationstag,
+        source: "https://web.archive.org/web/20160306062414/http://sl.juris.de/cgi-bin/landesrecht.py?d=http%3A%2F%2Fsl.juris.de%2Fsl%2Fgesamt%2FRefT2017V_SL.htm",
+    },
+    OneOffHoliday {
+        year: 2017,
+        region: Bundesweit,
+        holiday: Reformationstag,
+        source: "Reformationstag 2017 was a public holiday in all states, see the entries of the individual states",
+    },
+    OneOffHoliday {
+        year: 2020,
+        region: Berlin,
+        holiday: TagDerBefreiung,
+        source: "Gesetz über einen einmaligen Feiertag aus Anlass des 75. Jahrestages der Befreiung vom Nationalsozialismus und der Beendigung des Zweiten Weltkriegs in Europa",
+    },
+    OneOffHoliday {
+        year: 2025,
+        region: Berlin,
+        holiday: TagDerBefreiung,
+        source: "Gesetz über einen einmaligen Feiertag aus Anlass des 80. Jahrestages der Befreiung vom Nationalsozialismus und der Beendigung des Zweiten Weltkriegs in Europa",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_off_holidays_are_not_regular_holidays() {
+        for one_off in ONE_OFF_HOLIDAYS {
+            assert!(!one_off
+                .region
+                .region_specific_holidays(one_off.year)
+                .any(|holiday| holiday == one_off.holiday));
+            assert!(one_off
+                .region
+                .iter_holidays_in_year(one_off.year)
+                .any(|holiday| holiday == one_off.holiday));
+            assert!(!one_off
+                .region
+                .iter_holidays_in_year(one_off.year + 1)
+                .any(|holiday| holiday == one_off.holiday));
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::civil;
+use crate::month_grid::MONTHS;
+use crate::regions::GermanRegion;
+
+const PAGE_WIDTH: f64 = 842.0;
+const PAGE_HEIGHT: f64 = 595.0;
+const MARGIN: f64 = 30.0;
+const GRID_TOP: f64 = 528.0;
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"];
+
+impl GermanRegion {
+    /// Renders a printable year planner for the given year as a single A4 page in landscape format,
+    /// with one column per month and public holidays highlighted and labeled.
+    ///
+    /// Returns the bytes of the PDF document.
+    pub fn year_planner_pdf(&self, year: i32) -> Vec<u8> {
+        let holidays = self.iter_holiday_ymds(year).collect::<Vec<_>>();
+        let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / 12.0;
+        let row_height = (GRID_TOP - MARGIN) / 31.0;
+        let mut content = Vec::new();
+        text(
+            &mut content,
+            "F2",
+            14.0,
+            MARGIN,
+            560.0,
+            &format!("Feiertage {} ({})", year, self.iso_code()),
+        );
+        for month in 1..=12 {
+            let x = MARGIN + f64::from(month - 1) * column_width;
+            text(
+                &mut content,
+                "F2",
+                8.0,
+                x + 2.0,
+                GRID_TOP + 5.0,
+                MONTHS[month as usize - 1],
+            );
+            for day in 1..=civil::days_in_month(year, month) {
+                let date = (year, month, day);
+                let y = GRID_TOP - f64::from(day) * row_height;
+                let weekday = civil::weekday_from_monday(date) as usize;
+                let holiday = holidays
+                    .iter()
+                    .find(|&&(holiday_date, _)| holiday_date == date);
+                let fill = if holiday.is_some() {
+                    Some("1 0.75 0.75")
+                } else if weekday >= 5 {
+                    Some("0.9 0.9 0.9")
+                } else {
+                    None
+                };
+                if let Some(fill) = fill {
+                    push(
+                        &mut content,
+                        &format!(
+                            "{} rg {:.2} {:.2} {:.2} {:.2} re f\n",
+                            fill, x, y, column_width, row_height
+                        ),
+                    );
+                }
+                push(
+                    &mut content,
+                    &format!(
+                        "0.6 G 0.3 w {:.2} {:.2} {:.2} {:.2} re S 0 g\n",
+                        x, y, column_width, row_height
+                    ),
+                );
+                let baseline = y + (row_height - 6.0) / 2.0 + 1.0;
+                text(
+                    &mut content,
+                    "F1",
+                    6.0,
+                    x + 2.0,
+                    baseline,
+                    &format!("{:02} {}", day, WEEKDAYS[weekday]),
+                );
+                if let Some((_, holiday)) = holiday {
+                    // clip long names to the cell
+                    push(
+                        &mut content,
+                        &format!(
+                            "q {:.2} {:.2} {:.2} {:.2} re W n\n",
+                            x,
+                            y,
+                            column_width - 1.0,
+                            row_height
+                        ),
+                    );
+                    text(
+                        &mut content,
+                        "F1",
+                        4.5,
+                        x + 19.0,
+                        baseline + 0.5,
+                        holiday.description(),
+                    );
+                    push(&mut content, "Q\n");
+                }
+            }
+        }
+        document(&content)
+    }
+}
+
+fn push(content: &mut Vec<u8>, operators: &str) {
+    content.extend_from_slice(operators.as_bytes());
+}
+
+/// Appends a text operation, encoding the text as WinAnsiEncoding.
+fn text(content: &mut Vec<u8>, font: &str, size: f64, x: f64, y: f64, text: &str) {
+    push(
+        content,
+        &format!("BT /{} {} Tf {:.2} {:.2} Td (", font, size, x, y),
+    );
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                content.push(b'\\');
+                content.push(c as u8);
+            }
+            // WinAnsiEncoding matches Latin-1 for all characters used in holiday and month names
+            ' '..='~' | '\u{a0}'..='\u{ff}' => content.push(c as u32 as u8),
+            _ => content.push(b'?'),
+        }
+    }
+    push(content, ") Tj ET\n");
+}
+
+/// Wraps a page content stream into a complete PDF document.
+fn document(content: &[u8]) -> Vec<u8> {
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_owned(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            PAGE_WIDTH, PAGE_HEIGHT
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_owned(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_owned(),
+    ];
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+    offsets.push(pdf.len());
+    pdf.extend_from_slice(
+        format!(
+            "{} 0 obj\n<< /Length {} >>\nstream\n",
+            objects.len() + 1,
+            content.len()
+        )
+        .as_bytes(),
+    );
+    pdf.extend_from_slice(content);
+    pdf.extend_from_slice(b"\nendstream\nendobj\n");
+    let xref = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1).as_bytes(),
+    );
+    for offset in offsets.iter() {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            offsets.len() + 1,
+            xref
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::regions::GermanRegion::*;
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn year_planner_pdf() {
+        let pdf = Bayern.year_planner_pdf(2024);
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(contains(&pdf, b"(Feiertage 2024 \\(DE-BY\\)) Tj"));
+        assert!(contains(&pdf, b"(M\xe4rz) Tj"));
+        assert!(contains(&pdf, b"(Mari\xe4 Himmelfahrt) Tj"));
+        assert!(!contains(&pdf, b"(Reformationstag) Tj"));
+        // every cross-reference entry points to the start of its object
+        let xref_start = pdf
+            .windows(5)
+            .rposition(|window| window == b"xref\n")
+            .unwrap();
+        let xref = String::from_utf8_lossy(&pdf[xref_start..]);
+        for (i, line) in xref.lines().skip(3).take(6).enumerate() {
+            let offset = line[..10].parse::<usize>().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj", i + 1).as_bytes()));
+        }
+    }
+}
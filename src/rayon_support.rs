@@ -0,0 +1,84 @@
+use core::ops::RangeInclusive;
+use rayon::prelude::*;
+
+use crate::calendar_date::CalendarDate;
+use crate::csv::{push_csv_header, push_csv_year};
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+#[cfg(feature = "chrono")]
+use crate::table::{holiday_table_rows, HolidayTableRow};
+
+/// Number of dates classified by a single task of `par_classify_dates`.
+const CHUNK_SIZE: usize = 4096;
+
+impl GermanRegion {
+    /// Same as `classify_dates`, but classifies chunks of the dates in parallel.
+    pub fn par_classify_dates<D: CalendarDate + Sync>(
+        &self,
+        dates: &[D],
+    ) -> Vec<Option<GermanHoliday>> {
+        dates
+            .par_chunks(CHUNK_SIZE)
+            .flat_map_iter(|chunk| self.classify_dates(chunk))
+            .collect()
+    }
+}
+
+/// Same as `to_csv`, but computes the years in parallel.
+pub fn par_to_csv(regions: &[GermanRegion], years: RangeInclusive<i32>) -> String {
+    let years = years
+        .into_par_iter()
+        .map(|year| {
+            let mut csv = String::new();
+            push_csv_year(&mut csv, regions, year);
+            csv
+        })
+        .collect::<Vec<_>>();
+    let mut csv = String::new();
+    push_csv_header(&mut csv);
+    csv.extend(years);
+    csv
+}
+
+/// Same as `holiday_table`, but computes all regions and years in parallel.
+#[cfg(feature = "chrono")]
+pub fn par_holiday_table(years: RangeInclusive<i32>) -> Vec<HolidayTableRow> {
+    GermanRegion::ALL
+        .par_iter()
+        .flat_map(|&region| {
+            years
+                .clone()
+                .into_par_iter()
+                .flat_map_iter(move |year| holiday_table_rows(region, year))
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn parallel_versions_agree() {
+        assert_eq!(
+            par_to_csv(&[Bayern, Sachsen], 2020..=2030),
+            crate::to_csv(&[Bayern, Sachsen], 2020..=2030)
+        );
+        assert_eq!(
+            par_holiday_table(2020..=2030),
+            crate::holiday_table(2020..=2030)
+        );
+        let dates = NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .iter_days()
+            .take(10_000)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Hessen.par_classify_dates(&dates),
+            Hessen.classify_dates(&dates)
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Alternative serde representations of `GermanRegion` for use with `#[serde(with = "…")]`.
+//!
+//! By default, regions are (de)serialized as the name of their variant, e.g. `"NordrheinWestfalen"`.
+//! The modules in here instead use the ISO code (`"DE-NW"`), the ASCII slug (`"nordrhein-westfalen"`)
+//! or the German name (`"Nordrhein-Westfalen"`):
+//!
+//! ```
+//! # #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Employee {
+//!     #[serde(with = "holiday_de::region_serde::iso_code")]
+//!     region: holiday_de::GermanRegion,
+//! }
+//! ```
+use core::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::Serializer;
+
+use crate::regions::GermanRegion;
+
+struct RegionVisitor {
+    expecting: &'static str,
+    to_str: fn(&GermanRegion) -> &'static str,
+}
+
+impl Visitor<'_> for RegionVisitor {
+    type Value = GermanRegion;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<GermanRegion, E> {
+        GermanRegion::ALL_WITH_BUNDESWEIT
+            .iter()
+            .copied()
+            .find(|region| (self.to_str)(region) == value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+fn serialize_with<S: Serializer>(
+    region: &GermanRegion,
+    to_str: fn(&GermanRegion) -> &'static str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(to_str(region))
+}
+
+fn deserialize_with<'de, D: Deserializer<'de>>(
+    expecting: &'static str,
+    to_str: fn(&GermanRegion) -> &'static str,
+    deserializer: D,
+) -> Result<GermanRegion, D::Error> {
+    deserializer.deserialize_str(RegionVisitor { expecting, to_str })
+}
+
+/// (De)serializes a region as its ISO code, see `GermanRegion::iso_code`.
+pub mod iso_code {
+    use serde::{Deserializer, Serializer};
+
+    use crate::regions::GermanRegion;
+
+    pub fn serialize<S: Serializer>(
+        region: &GermanRegion,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_with(region, GermanRegion::iso_code, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GermanRegion, D::Error> {
+        super::deserialize_with(
+            "an ISO code of a German region",
+            GermanRegion::iso_code,
+            deserializer,
+        )
+    }
+}
+
+/// (De)serializes a region as its ASCII slug, see `GermanRegion::slug`.
+pub mod slug {
+    use serde::{Deserializer, Serializer};
+
+    use crate::regions::GermanRegion;
+
+    pub fn serialize<S: Serializer>(
+        region: &GermanRegion,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_with(region, GermanRegion::slug, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GermanRegion, D::Error> {
+        super::deserialize_with(
+            "a slug of a German region",
+            GermanRegion::slug,
+            deserializer,
+        )
+    }
+}
+
+/// (De)serializes a region as its German name, see `GermanRegion::name`.
+pub mod name {
+    use serde::{Deserializer, Serializer};
+
+    use crate::regions::GermanRegion;
+
+    pub fn serialize<S: Serializer>(
+        region: &GermanRegion,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize_with(region, GermanRegion::name, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GermanRegion, D::Error> {
+        super::deserialize_with(
+            "the name of a German region",
+            GermanRegion::name,
+            deserializer,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::regions::GermanRegion::{self, *};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Regions {
+        #[serde(with = "super::iso_code")]
+        iso_code: GermanRegion,
+        #[serde(with = "super::slug")]
+        slug: GermanRegion,
+        #[serde(with = "super::name")]
+        name: GermanRegion,
+    }
+
+    #[test]
+    fn representations() {
+        let regions = Regions {
+            iso_code: NordrheinWestfalen,
+            slug: BadenWuerttemberg,
+            name: Thueringen,
+        };
+        let json = serde_json::to_string(&regions).unwrap();
+        assert_eq!(
+            json,
+            r#"{"iso_code":"DE-NW","slug":"baden-wuerttemberg","name":"Thüringen"}"#
+        );
+        assert_eq!(serde_json::from_str::<Regions>(&json).unwrap(), regions);
+        assert!(serde_json::from_str::<Regions>(
+            r#"{"iso_code":"NordrheinWestfalen","slug":"bw","name":"Thüringen"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn all_regions_roundtrip() {
+        for &region in GermanRegion::ALL_WITH_BUNDESWEIT {
+            let regions = Regions {
+                iso_code: region,
+                slug: region,
+                name: region,
+            };
+            let json = serde_json::to_string(&regions).unwrap();
+            assert_eq!(serde_json::from_str::<Regions>(&json).unwrap(), regions);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::calendar_date::CalendarDate;
+use crate::civil::{self, Ymd};
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// Marks days without a public holiday in `RegionYear::holiday_indices`.
+const NO_HOLIDAY: u8 = u8::MAX;
+
+/// The public holidays of a region in a single year as a bitset over the days of the year,
+/// answering `is_holiday` and `holiday_on` in constant time without any allocation.
+///
+/// Unlike `YearCalendar`, this is meant for tight loops rather than for caching outside of the process.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegionYear {
+    region: GermanRegion,
+    year: i32,
+    /// One bit per day of the year, starting with January 1st as bit 0.
+    holiday_bits: [u64; 6],
+    /// Index into `GermanHoliday::ALL` per day of the year, or `NO_HOLIDAY`.
+    holiday_indices: [u8; 366],
+}
+
+impl GermanRegion {
+    /// Precomputes the public holidays in the given year, see `RegionYear`.
+    pub fn region_year(&self, year: i32) -> RegionYear {
+        let mut region_year = RegionYear {
+            region: *self,
+            year,
+            holiday_bits: [0; 6],
+            holiday_indices: [NO_HOLIDAY; 366],
+        };
+        for (date, holiday) in self.iter_holiday_ymds(year) {
+            let ordinal = ordinal(date).expect("holiday dates are valid");
+            // holidays on the same date are sorted in the order of `GermanHoliday::ALL`,
+            // keep the first one like `holiday_from_date`
+            if region_year.holiday_indices[ordinal] == NO_HOLIDAY {
+                let index = GermanHoliday::ALL
+                    .iter()
+                    .position(|&other| other == holiday)
+                    .expect("all holidays are listed");
+                region_year.holiday_bits[ordinal / 64] |= 1 << (ordinal % 64);
+                region_year.holiday_indices[ordinal] = index as u8;
+            }
+        }
+        region_year
+    }
+
+    /// Returns the public holiday, if any, for each of the given dates.
+    ///
+    /// The holidays of each year are computed only once, regardless of the order of the dates.
+    #[cfg(feature = "alloc")]
+    pub fn classify_dates<D: CalendarDate>(&self, dates: &[D]) -> Vec<Option<GermanHoliday>> {
+        let mut region_years = BTreeMap::new();
+        dates
+            .iter()
+            .map(|date| {
+                let (year, _, _) = date.to_ymd();
+                region_years
+                    .entry(year)
+                    .or_insert_with(|| self.region_year(year))
+                    .holiday_from_date(date)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the given dates together with their public holiday, if any.
+    ///
+    /// Same as `classify_dates`, but without allocating. The holidays of a year are computed again
+    /// whenever consecutive dates differ in their year, so this works best for sorted dates.
+    pub fn iter_classify_dates<D: CalendarDate, I: IntoIterator<Item = D>>(
+        &self,
+        dates: I,
+    ) -> impl Iterator<Item = (D, Option<GermanHoliday>)> {
+        let region = *self;
+        let mut region_year = None::<RegionYear>;
+        dates.into_iter().map(move |date| {
+            let (year, _, _) = date.to_ymd();
+            if region_year.as_ref().map(RegionYear::year) != Some(year) {
+                region_year = Some(region.region_year(year));
+            }
+            let holiday = region_year
+                .as_ref()
+                .and_then(|region_year| region_year.holiday_from_date(&date));
+            (date, holiday)
+        })
+    }
+}
+
+impl RegionYear {
+    /// The region of these holidays.
+    pub fn region(&self) -> GermanRegion {
+        self.region
+    }
+
+    /// The year of these holidays.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Number of days in the year which are public holidays.
+    pub fn holiday_count(&self) -> u32 {
+        self.holiday_bits.iter().map(|bits| bits.count_ones()).sum()
+    }
+
+    /// True if the given day of the year is a public holiday.
+    ///
+    /// Always `false` for invalid dates.
+    pub fn is_holiday(&self, month: u32, day: u32) -> bool {
+        ordinal((self.year, month, day))
+            .is_some_and(|ordinal| self.holiday_bits[ordinal / 64] & (1 << (ordinal % 64)) != 0)
+    }
+
+    /// Returns the public holiday on the given day of the year, if any.
+    pub fn holiday_on(&self, month: u32, day: u32) -> Option<GermanHoliday> {
+        let index = self.holiday_indices[ordinal((self.year, month, day))?];
+        GermanHoliday::ALL.get(usize::from(index)).copied()
+    }
+
+    /// Returns the public holiday on the given date, if any.
+    ///
+    /// Always `None` for dates outside of this year.
+    pub fn holiday_from_date<D: CalendarDate>(&self, date: &D) -> Option<GermanHoliday> {
+        let (year, month, day) = date.to_ymd();
+        if year == self.year {
+            self.holiday_on(month, day)
+        } else {
+            None
+        }
+    }
+}
+
+/// Zero-based day of the year, `None` for invalid dates.
+fn ordinal((year, month, day): Ymd) -> Option<usize> {
+    if !(1..=12).contains(&month) || day == 0 || day > civil::days_in_month(year, month) {
+        return None;
+    }
+    Some(
+        (civil::days_from_civil((year, month, day)) - civil::days_from_civil((year, 1, 1)))
+            as usize,
+    )
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::{Datelike, NaiveDate};
+    use std::collections::BTreeSet;
+
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn region_year_agrees_with_region() {
+        for &region in GermanRegion::ALL {
+            for year in [1989, 2008, 2024] {
+                let region_year = region.region_year(year);
+                // in 2008, Erster Mai and Christi Himmelfahrt fall on the same day
+                let holiday_dates = region
+                    .iter_holiday_dates(year)
+                    .map(|(date, _)| date)
+                    .collect::<BTreeSet<_>>();
+                assert_eq!(region_year.holiday_count() as usize, holiday_dates.len());
+                let first = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+                for date in first.iter_days().take_while(|date| date.year() == year) {
+                    assert_eq!(
+                        region_year.is_holiday(date.month(), date.day()),
+                        region.is_holiday(date)
+                    );
+                    assert_eq!(
+                        region_year.holiday_from_date(&date),
+                        region.holiday_from_date(date)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn region_year_keeps_first_holiday_on_same_date() {
+        for &region in GermanRegion::ALL {
+            assert_eq!(
+                region.region_year(2008).holiday_on(5, 1),
+                region.holiday_from_ymd((2008, 5, 1))
+            );
+            assert_eq!(region.region_year(2008).holiday_on(5, 1), Some(ErsterMai));
+        }
+    }
+
+    #[test]
+    fn classify_dates() {
+        let dates = [
+            (2024, 10, 3),
+            (2023, 1, 1),
+            (2024, 10, 4),
+            (2023, 4, 7),
+            (2024, 3, 8),
+        ]
+        .iter()
+        .map(|&(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+        .collect::<Vec<_>>();
+        let expected = dates
+            .iter()
+            .map(|&date| Berlin.holiday_from_date(date))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expected,
+            [
+                Some(TagDerDeutschenEinheit),
+                Some(Neujahr),
+                None,
+                Some(Karfreitag),
+                Some(Frauentag)
+            ]
+        );
+        assert_eq!(Berlin.classify_dates(&dates), expected);
+        assert!(Berlin
+            .iter_classify_dates(dates.iter().copied())
+            .eq(dates.iter().copied().zip(expected)));
+    }
+
+    #[test]
+    fn region_year_lookups() {
+        let region_year = Berlin.region_year(2024);
+        assert_eq!(region_year.holiday_on(3, 8), Some(Frauentag));
+        assert_eq!(region_year.holiday_on(12, 31), None);
+        assert!(region_year.is_holiday(12, 26));
+        assert!(!region_year.is_holiday(2, 30));
+        assert!(!region_year.is_holiday(13, 1));
+        assert_eq!(
+            region_year.holiday_from_date(&NaiveDate::from_ymd_opt(2025, 3, 8).unwrap()),
+            None
+        );
+    }
+}
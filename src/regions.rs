@@ -1,14 +1,42 @@
-use chrono::{Datelike, NaiveDate};
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "chrono")]
+use chrono::Weekday;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Duration, NaiveDate};
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+use core::fmt;
+use core::iter;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
 
 /// Represents all regions and their public holidays within Germany.
 ///
 /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
 /// However, holidays with a fixed date can still fall on a sunday.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text, sql_type = diesel::sql_types::SmallInt)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq), derive(Clone, Copy, Debug, Eq, PartialEq))
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum GermanRegion {
     BadenWuerttemberg,
     /// * The Augsburger Friedensfest only applies to Augsburg.
     ///   It is excluded by default, but can be calculated via `GermanHoliday::AugsburgerFriedensfest`.
+    ///   Use `is_augsburg_ags` or `is_augsburg_plz` to check whether an address lies within Augsburg.
     /// * Mariä Himmelfahrt only applies to communities with a catholic majority.
     ///   Since this is the case in the majority of communities, it is included by default.
     Bayern,
@@ -30,137 +58,809 @@ pub enum GermanRegion {
     /// Fronleichnam applies only to a minority of communities and has been excluded by default.
     /// It can be manually calculated via `GermanHoliday::Fronleichnam`.
     Thueringen,
+    /// Not a federal state, but only the holidays which apply in all of Germany
+    /// (gesetzliche bundesweite Feiertage).
+    ///
+    /// Not included in `GermanRegion::ALL`, see `GermanRegion::ALL_WITH_BUNDESWEIT`.
+    Bundesweit,
+}
+
+/// A holiday together with its date in a specific year.
+#[cfg(feature = "chrono")]
+pub type DatedHoliday = (NaiveDate, GermanHoliday);
+
+/// Summary of a single day within a region, see `GermanRegion::describe_day`.
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DayDescription {
+    /// The described date.
+    pub date: NaiveDate,
+    /// The public holiday on this date, if any.
+    pub public_holiday: Option<GermanHoliday>,
+    /// Holidays on this date which are not public holidays in the region, e.g. Heiligabend.
+    pub observances: Vec<GermanHoliday>,
+    /// True if this date is a bridge day, see `GermanRegion::is_bridge_day`.
+    pub bridge_day: bool,
+    /// Language used by the `Display` implementation.
+    pub language: Language,
+}
+
+/// Free days within a range of dates, see `GermanRegion::free_days_between`.
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FreeDays {
+    /// Saturdays, sundays and public holidays in chronological order.
+    pub days: Vec<NaiveDate>,
+    /// Heiligabend and Silvester on working days, on which work customarily ends at noon.
+    pub half_days: Vec<DatedHoliday>,
+}
+
+/// Formats the description as a single line in its language,
+/// e.g. `2024-10-03: Tag der Deutschen Einheit (gesetzlicher Feiertag)`.
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+impl fmt::Display for DayDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (public, bridge_day, none) = match self.language {
+            Language::German => ("gesetzlicher Feiertag", "Brückentag", "kein Feiertag"),
+            Language::English => ("public holiday", "bridge day", "no holiday"),
+        };
+        write!(f, "{}: ", self.date)?;
+        let mut parts = self
+            .public_holiday
+            .map(|holiday| (holiday.localized_name(self.language), Some(public)))
+            .into_iter()
+            .chain(
+                self.observances
+                    .iter()
+                    .map(|holiday| (holiday.localized_name(self.language), None)),
+            )
+            .chain(self.bridge_day.then_some((bridge_day, None)))
+            .peekable();
+        if parts.peek().is_none() {
+            return write!(f, "{}", none);
+        }
+        for (index, (name, note)) in parts.enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", name)?;
+            if let Some(note) = note {
+                write!(f, " ({})", note)?;
+            }
+        }
+        Ok(())
+    }
 }
 
-use crate::holidays::GermanHoliday;
+use crate::bundesland::Bundesland;
+use crate::calendar_date::CalendarDate;
+use crate::civil::Ymd;
+#[cfg(feature = "chrono")]
+use crate::clock::Clock;
+#[cfg(any(feature = "alloc", feature = "chrono"))]
+use crate::error::HolidayError;
+use crate::error::ParseRegionError;
 use crate::holidays::GermanHoliday::*;
+#[cfg(all(feature = "alloc", feature = "chrono"))]
+use crate::holidays::Language;
+use crate::holidays::{self, GermanHoliday, HolidayType, HolidayYear};
+use crate::one_off::ONE_OFF_HOLIDAYS;
 use crate::regions::GermanRegion::*;
 
 impl GermanRegion {
+    /// All federal states in alphabetical order, without `Bundesweit`.
+    pub const ALL: &'static [GermanRegion] = &[
+        BadenWuerttemberg,
+        Bayern,
+        Berlin,
+        Brandenburg,
+        Bremen,
+        Hamburg,
+        Hessen,
+        MechlenburgVorpommern,
+        Niedersachsen,
+        NordrheinWestfalen,
+        RheinlandPfalz,
+        Saarland,
+        Sachsen,
+        SachsenAnhalt,
+        SchleswigHolstein,
+        Thueringen,
+    ];
+
+    /// All federal states in alphabetical order, followed by `Bundesweit`.
+    pub const ALL_WITH_BUNDESWEIT: &'static [GermanRegion] = &[
+        BadenWuerttemberg,
+        Bayern,
+        Berlin,
+        Brandenburg,
+        Bremen,
+        Hamburg,
+        Hessen,
+        MechlenburgVorpommern,
+        Niedersachsen,
+        NordrheinWestfalen,
+        RheinlandPfalz,
+        Saarland,
+        Sachsen,
+        SachsenAnhalt,
+        SchleswigHolstein,
+        Thueringen,
+        Bundesweit,
+    ];
+
+    /// Returns the German name of the region, e.g. `"Baden-Württemberg"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "Baden-Württemberg",
+            Bayern => "Bayern",
+            Berlin => "Berlin",
+            Brandenburg => "Brandenburg",
+            Bremen => "Bremen",
+            Hamburg => "Hamburg",
+            Hessen => "Hessen",
+            MechlenburgVorpommern => "Mecklenburg-Vorpommern",
+            Niedersachsen => "Niedersachsen",
+            NordrheinWestfalen => "Nordrhein-Westfalen",
+            RheinlandPfalz => "Rheinland-Pfalz",
+            Saarland => "Saarland",
+            Sachsen => "Sachsen",
+            SachsenAnhalt => "Sachsen-Anhalt",
+            SchleswigHolstein => "Schleswig-Holstein",
+            Thueringen => "Thüringen",
+            Bundesweit => "Bundesweit",
+        }
+    }
+
+    /// Returns the name of the variant, e.g. `"NordrheinWestfalen"`,
+    /// which is also used by the serde implementations.
+    pub const fn identifier(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "BadenWuerttemberg",
+            Bayern => "Bayern",
+            Berlin => "Berlin",
+            Brandenburg => "Brandenburg",
+            Bremen => "Bremen",
+            Hamburg => "Hamburg",
+            Hessen => "Hessen",
+            MechlenburgVorpommern => "MechlenburgVorpommern",
+            Niedersachsen => "Niedersachsen",
+            NordrheinWestfalen => "NordrheinWestfalen",
+            RheinlandPfalz => "RheinlandPfalz",
+            Saarland => "Saarland",
+            Sachsen => "Sachsen",
+            SachsenAnhalt => "SachsenAnhalt",
+            SchleswigHolstein => "SchleswigHolstein",
+            Thueringen => "Thueringen",
+            Bundesweit => "Bundesweit",
+        }
+    }
+
+    /// Returns the English name of the region, e.g. `"Bavaria"` or `"North Rhine-Westphalia"`.
+    pub const fn english_name(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "Baden-Württemberg",
+            Bayern => "Bavaria",
+            Berlin => "Berlin",
+            Brandenburg => "Brandenburg",
+            Bremen => "Bremen",
+            Hamburg => "Hamburg",
+            Hessen => "Hesse",
+            MechlenburgVorpommern => "Mecklenburg-Western Pomerania",
+            Niedersachsen => "Lower Saxony",
+            NordrheinWestfalen => "North Rhine-Westphalia",
+            RheinlandPfalz => "Rhineland-Palatinate",
+            Saarland => "Saarland",
+            Sachsen => "Saxony",
+            SachsenAnhalt => "Saxony-Anhalt",
+            SchleswigHolstein => "Schleswig-Holstein",
+            Thueringen => "Thuringia",
+            Bundesweit => "Nationwide",
+        }
+    }
+
+    /// Returns the official German name of the region as used in the state constitutions,
+    /// e.g. `"Freistaat Bayern"` or `"Freie und Hansestadt Hamburg"`, and the same as `name` otherwise.
+    pub const fn official_name(&self) -> &'static str {
+        match self {
+            Bayern => "Freistaat Bayern",
+            Bremen => "Freie Hansestadt Bremen",
+            Hamburg => "Freie und Hansestadt Hamburg",
+            Sachsen => "Freistaat Sachsen",
+            Thueringen => "Freistaat Thüringen",
+            Bundesweit => "Bundesrepublik Deutschland",
+            _ => self.name(),
+        }
+    }
+
+    /// Returns a lowercase ASCII identifier of the region for use in URLs, e.g. `"baden-wuerttemberg"`.
+    pub const fn slug(&self) -> &'static str {
+        match self {
+            BadenWuerttemberg => "baden-wuerttemberg",
+            Bayern => "bayern",
+            Berlin => "berlin",
+            Brandenburg => "brandenburg",
+            Bremen => "bremen",
+            Hamburg => "hamburg",
+            Hessen => "hessen",
+            MechlenburgVorpommern => "mecklenburg-vorpommern",
+            Niedersachsen => "niedersachsen",
+            NordrheinWestfalen => "nordrhein-westfalen",
+            RheinlandPfalz => "rheinland-pfalz",
+            Saarland => "saarland",
+            Sachsen => "sachsen",
+            SachsenAnhalt => "sachsen-anhalt",
+            SchleswigHolstein => "schleswig-holstein",
+            Thueringen => "thueringen",
+            Bundesweit => "bundesweit",
+        }
+    }
+
+    /// Returns the ISO 3166-2 code of the region, e.g. `"DE-BY"` for Bayern,
+    /// or the ISO 3166-1 code `"DE"` for `Bundesweit`.
+    pub const fn iso_code(&self) -> &'static str {
+        match self.iso_3166_2() {
+            Some(code) => code,
+            None => "DE",
+        }
+    }
+
+    /// Returns the ISO 3166-2 subdivision code of the region's federal state, e.g. `"DE-BY"` for Bayern,
+    /// or `None` for `Bundesweit`, which is not a subdivision.
+    ///
+    /// All regions within the same state share the code of that state.
+    pub const fn iso_3166_2(&self) -> Option<&'static str> {
+        match self.bundesland() {
+            Some(state) => Some(state.iso_3166_2()),
+            None => None,
+        }
+    }
+
+    /// Returns the region for an ISO 3166-2 subdivision code like `"DE-BY"`, ignoring case.
+    ///
+    /// Since a code only identifies a federal state, this returns the state's `Bundesland::default_region`.
+    /// Unlike `from_str`, only the full code is accepted.
+    pub fn from_iso_3166_2(code: &str) -> Option<GermanRegion> {
+        Bundesland::ALL
+            .iter()
+            .find(|state| state.iso_3166_2().eq_ignore_ascii_case(code))
+            .map(Bundesland::default_region)
+    }
+
+    /// The ISO 3166-2 code without the country prefix, e.g. `"BY"` for Bayern, or `"DE"` for `Bundesweit`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn short_code(&self) -> &'static str {
+        let code = self.iso_code();
+        code.get(3..).unwrap_or(code)
+    }
+
     /// Returns all public holidays in the given year.
     /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
     ///
-    /// For years before 1995 this list will be empty.
+    /// For years before 1990 this list will be empty.
+    /// Until 1994, Buß- und Bettag was a public holiday in all regions.
+    /// In 1990, the regional holidays of the eastern states are not included,
+    /// as their holiday laws only took effect in 1991, and 17 June is only included
+    /// for the western states, as `Berlin` refers to the whole city from 1990 on.
+    ///
+    /// Use `iter_holidays_in_year` to avoid allocating.
+    #[cfg(feature = "alloc")]
     pub fn holidays_in_year(&self, year: i32) -> Vec<GermanHoliday> {
-        if year < 1995 {
-            return Vec::new();
-        }
-        let mut holidays = Vec::new();
-        holidays.extend_from_slice(BUNDESWEITE_FEIERTAGE);
-        holidays.extend_from_slice(self.region_specific_holidays(year));
-        if year == 2017 && !holidays.contains(&Reformationstag) {
-            // BW: https://www.landesrecht-bw.de/perma?d=jlr-FeiertGBWV1P1a
-            // BY: https://www.bayern.landtag.de/www/ElanTextAblage_WP17/Drucksachen/Folgedrucksachen/0000007000/0000007463.pdf
-            // BE: https://gesetze.berlin.de/bsbe/document/aiz-jlr-FeiertGBErahmen%4020151025/part/x
-            // HE: https://www.rv.hessenrecht.hessen.de/bshe/document/jlr-RefT2017VHErahmen/part/X
-            // NW: https://www.landtag.nrw.de/portal/WWW/dokumentenarchiv/Dokument?Id=XMMGVB1528%7C496%7C496
-            // RP: https://web.archive.org/web/20160305005630/https://www.rlp.de/fr/aktuelles/einzelansicht/news/detail/News/zusaetzlicher-feiertag-2017/
-            // SL: https://web.archive.org/web/20160306062414/http://sl.juris.de/cgi-bin/landesrecht.py?d=http%3A%2F%2Fsl.juris.de%2Fsl%2Fgesamt%2FRefT2017V_SL.htm
-            holidays.push(Reformationstag);
-        }
-        holidays
-    }
-
-    fn region_specific_holidays(&self, year: i32) -> &'static [GermanHoliday] {
-        match self {
-            BadenWuerttemberg => &[HeiligeDreiKoenige, Fronleichnam, Allerheiligen],
-            Bayern => &[
-                HeiligeDreiKoenige,
-                Fronleichnam,
-                MariaeHimmelfahrt,
-                Allerheiligen,
-            ],
-            Berlin => {
-                if year >= 2019 {
-                    &[Frauentag]
-                } else {
-                    &[]
-                }
-            }
-            Brandenburg => &[Reformationstag],
-            Bremen => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            Hamburg => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            Hessen => &[Fronleichnam],
-            MechlenburgVorpommern => {
-                if year >= 2023 {
-                    &[Frauentag, Reformationstag]
-                } else {
-                    &[Reformationstag]
-                }
-            }
-            Niedersachsen => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            NordrheinWestfalen => &[Fronleichnam, Allerheiligen],
-            RheinlandPfalz => &[Fronleichnam, Allerheiligen],
-            Saarland => &[Fronleichnam, MariaeHimmelfahrt, Allerheiligen],
-            Sachsen => &[Reformationstag, BussUndBettag],
-            SachsenAnhalt => &[HeiligeDreiKoenige, Reformationstag],
-            SchleswigHolstein => {
-                if year >= 2017 {
-                    &[Reformationstag]
-                } else {
-                    &[]
-                }
-            }
-            Thueringen => {
-                if year >= 2019 {
-                    &[Weltkindertag, Reformationstag]
-                } else {
-                    &[Reformationstag]
-                }
-            }
+        self.iter_holidays_in_year(year).collect()
+    }
+
+    /// Returns all public holidays in the given year, see `holidays_in_year`.
+    ///
+    /// Returns `HolidayError::UnsupportedYear` for years outside of `supported_year_range()`.
+    #[cfg(feature = "alloc")]
+    pub fn try_holidays_in_year(&self, year: i32) -> Result<Vec<GermanHoliday>, HolidayError> {
+        check_year(year)?;
+        Ok(self.holidays_in_year(year))
+    }
+
+    /// Returns an iterator over all public holidays in the given year.
+    ///
+    /// Same as `holidays_in_year`, but without allocating.
+    pub fn iter_holidays_in_year(&self, year: i32) -> impl Iterator<Item = GermanHoliday> {
+        let supported = year >= FIRST_YEAR;
+        let national = if supported {
+            BUNDESWEITE_FEIERTAGE
+        } else {
+            &[]
+        };
+        let region = *self;
+        let one_off_holidays = ONE_OFF_HOLIDAYS
+            .iter()
+            .filter(move |one_off| one_off.year == year && one_off.region == region)
+            .map(|one_off| one_off.holiday);
+        national
+            .iter()
+            .copied()
+            .chain(
+                self.region_specific_holidays(year)
+                    .filter(move |_| supported),
+            )
+            .chain(one_off_holidays)
+    }
+
+    pub(crate) fn region_specific_holidays(
+        &self,
+        year: i32,
+    ) -> impl Iterator<Item = GermanHoliday> {
+        self.rules()
+            .filter(move |rule| rule.applies_in(year))
+            .map(|rule| rule.holiday)
+    }
+
+    /// Classifies a holiday in the given year as either `HolidayType::Public`
+    /// if it is a public holiday in this region, or `HolidayType::Observance` otherwise.
+    pub fn holiday_type(&self, holiday: GermanHoliday, year: i32) -> HolidayType {
+        if self
+            .iter_holidays_in_year(year)
+            .any(|public| public == holiday)
+        {
+            HolidayType::Public
+        } else {
+            HolidayType::Observance
         }
     }
 
+    /// Returns all holidays and their dates in the given year as any supported date type,
+    /// e.g. `time::Date` if the `time` feature is enabled.
+    ///
+    /// Holidays whose date cannot be represented by the date type are skipped.
+    #[cfg(feature = "alloc")]
+    pub fn holiday_dates_in_year_as<D: CalendarDate>(&self, year: i32) -> Vec<(D, GermanHoliday)> {
+        self.iter_holiday_dates_as(year).collect()
+    }
+
+    /// Returns an iterator over all holidays and their dates in the given year in chronological order.
+    ///
+    /// Same as `holiday_dates_in_year_as`, but without allocating.
+    pub fn iter_holiday_dates_as<D: CalendarDate>(
+        &self,
+        year: i32,
+    ) -> impl DoubleEndedIterator<Item = (D, GermanHoliday)> {
+        self.iter_holiday_ymds(year)
+            .flat_map(|((year, month, day), holiday)| {
+                D::from_ymd(year, month, day).map(|date| (date, holiday))
+            })
+    }
+
+    pub(crate) fn iter_holiday_ymds(
+        &self,
+        year: i32,
+    ) -> impl DoubleEndedIterator<Item = (Ymd, GermanHoliday)> {
+        holidays::sorted_ymds(self.iter_holidays_in_year(year), year)
+    }
+
+    pub(crate) fn holiday_from_ymd(&self, date: Ymd) -> Option<GermanHoliday> {
+        let (year, month, day) = date;
+        let holiday_year = HolidayYear::new(year);
+        // if two holidays fall on the same date, the first one in `GermanHoliday::ALL` is returned
+        self.iter_holidays_in_year(year)
+            .filter(|&holiday| holiday_year.is_on(holiday, (month, day)))
+            .min_by_key(|&holiday| holiday as usize)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl GermanRegion {
     /// Returns all holidays and their dates in the given year.
     /// Holidays guaranteed to take place on sundays, e.g. easter sunday, are excluded by default.
     ///
-    /// For years before 1995 this list will be empty.
+    /// For years before 1990 this list will be empty.
+    ///
+    /// Use `iter_holiday_dates` or `holiday_dates_in_year_array` to avoid allocating.
+    #[cfg(feature = "alloc")]
     pub fn holiday_dates_in_year(&self, year: i32) -> Vec<(NaiveDate, GermanHoliday)> {
-        let mut holiday_dates: Vec<(NaiveDate, GermanHoliday)> = self
-            .holidays_in_year(year)
-            .into_iter()
-            .flat_map(|holiday| holiday.date(year).map(|date| (date, holiday)))
-            .collect();
-        holiday_dates.sort_unstable_by_key(|(date, _)| *date);
-        holiday_dates
+        self.iter_holiday_dates(year).collect()
+    }
+
+    /// Returns an iterator over all holidays and their dates in the given year in chronological order.
+    ///
+    /// Same as `holiday_dates_in_year`, but without allocating.
+    pub fn iter_holiday_dates(&self, year: i32) -> impl DoubleEndedIterator<Item = DatedHoliday> {
+        self.iter_holiday_dates_as(year)
+    }
+
+    /// Returns all holidays and their dates in the given year in chronological order
+    /// as a fixed-size array, together with the number of holidays.
+    ///
+    /// Same as `holiday_dates_in_year`, but without allocating.
+    /// The first `len` entries are `Some`, all following ones are `None`.
+    pub fn holiday_dates_in_year_array(
+        &self,
+        year: i32,
+    ) -> ([Option<DatedHoliday>; MAX_HOLIDAYS_PER_YEAR], usize) {
+        let mut holiday_dates = [None; MAX_HOLIDAYS_PER_YEAR];
+        let mut len = 0;
+        for holiday_date in self.iter_holiday_dates(year) {
+            holiday_dates[len] = Some(holiday_date);
+            len += 1;
+        }
+        (holiday_dates, len)
+    }
+
+    /// Returns all holidays in the given year keyed by their date.
+    ///
+    /// If two holidays fall on the same date, e.g. Erster Mai and Christi Himmelfahrt in 2008,
+    /// the one returned by `holiday_from_date` is kept.
+    #[cfg(feature = "alloc")]
+    pub fn holiday_date_map(&self, year: i32) -> BTreeMap<NaiveDate, GermanHoliday> {
+        let mut map = BTreeMap::new();
+        for holiday in self.iter_holidays_in_year(year) {
+            if let Some(date) = holiday.date(year) {
+                map.entry(date).or_insert(holiday);
+            }
+        }
+        map
     }
 
     /// Checks if a given date is a public holiday in the specific region.
     ///
-    /// Always `false` for dates before 1995.
+    /// Always `false` for dates before 1990.
     pub fn is_holiday(&self, date: NaiveDate) -> bool {
         self.holiday_from_date(date).is_some()
     }
 
+    /// True if the given date is a bridge day (Brückentag) in the specific region:
+    /// a working day between a public holiday and a weekend.
+    pub fn is_bridge_day(&self, date: NaiveDate) -> bool {
+        let is_weekend = |date: NaiveDate| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        let (Some(previous), Some(next)) = (date.pred_opt(), date.succ_opt()) else {
+            return false;
+        };
+        !is_weekend(date)
+            && !self.is_holiday(date)
+            && ((self.is_holiday(previous) && is_weekend(next))
+                || (is_weekend(previous) && self.is_holiday(next)))
+    }
+
     /// Returns the holiday for a specific date if the date is a holiday in the specific region.
     ///
-    /// Always `None` for dates before 1995.
+    /// Always `None` for dates before 1990.
     pub fn holiday_from_date(&self, date: NaiveDate) -> Option<GermanHoliday> {
-        self.holidays_in_year(date.year())
+        self.holiday_from_ymd(date.to_ymd())
+    }
+
+    /// Returns the holiday for a specific date if the date is a holiday in the specific region,
+    /// see `holiday_from_date`.
+    ///
+    /// Returns `HolidayError::UnsupportedYear` for dates outside of `supported_year_range()`.
+    pub fn try_holiday_from_date(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<GermanHoliday>, HolidayError> {
+        check_year(date.year())?;
+        Ok(self.holiday_from_date(date))
+    }
+
+    /// Returns the public holiday, all other holidays falling on the given date
+    /// and whether it is a bridge day, formatted in the given language by its `Display` implementation.
+    ///
+    /// Historical holidays like Tag der Republik are only listed in years
+    /// in which they are a public holiday in at least one region.
+    ///
+    /// School holidays, stille Tage and shop opening rules are not covered by this crate.
+    #[cfg(feature = "alloc")]
+    pub fn describe_day(&self, date: NaiveDate, language: Language) -> DayDescription {
+        let public_holiday = self.holiday_from_date(date);
+        let observances = GermanHoliday::ALL
+            .iter()
+            .copied()
+            .filter(|holiday| Some(*holiday) != public_holiday)
+            .filter(|holiday| holiday.date(date.year()) == Some(date))
+            .filter(|holiday| {
+                !holiday.is_historical()
+                    || GermanRegion::ALL.iter().any(|region| {
+                        region
+                            .iter_holidays_in_year(date.year())
+                            .any(|public| public == *holiday)
+                    })
+            })
+            .collect();
+        DayDescription {
+            date,
+            public_holiday,
+            observances,
+            bridge_day: self.is_bridge_day(date),
+            language,
+        }
+    }
+
+    /// Returns all public holidays between `from` and `to` (both inclusive) in chronological order.
+    /// The range may span multiple years.
+    #[cfg(feature = "alloc")]
+    pub fn holidays_between(&self, from: NaiveDate, to: NaiveDate) -> Vec<DatedHoliday> {
+        (from.year()..=to.year())
+            .flat_map(|year| self.iter_holiday_dates(year))
+            .filter(|(date, _)| from <= *date && *date <= to)
+            .collect()
+    }
+
+    /// Returns all public holidays from `today` (inclusive) until `today + horizon` (inclusive) in chronological order.
+    ///
+    /// Empty for negative horizons.
+    #[cfg(feature = "alloc")]
+    pub fn upcoming_holidays(&self, today: NaiveDate, horizon: Duration) -> Vec<DatedHoliday> {
+        let end = saturating_add(today, horizon);
+        self.holidays_between(today, end)
+    }
+
+    /// Returns an iterator of `(reminder_date, holiday_date, holiday)` for every public holiday
+    /// whose reminder date, `lead_time` before the holiday, lies between `from` and `to` (both inclusive).
+    ///
+    /// Reminders within the range may refer to holidays after `to`, e.g. in the following year.
+    /// Holidays whose reminder date cannot be represented are skipped.
+    pub fn reminders(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        lead_time: Duration,
+    ) -> impl Iterator<Item = (NaiveDate, NaiveDate, GermanHoliday)> {
+        let start = saturating_add(from, lead_time);
+        let end = saturating_add(to, lead_time);
+        self.iter_holidays_from(start)
+            .take_while(move |(date, _)| *date <= end)
+            .filter_map(move |(date, holiday)| {
+                let reminder = date.checked_sub_signed(lead_time)?;
+                Some((reminder, date, holiday))
+            })
+    }
+
+    /// Returns all days between `from` and `to` (both inclusive) which are either
+    /// a saturday, a sunday or a public holiday. The range may span multiple years.
+    ///
+    /// Heiligabend and Silvester are not public holidays, so when they fall on a working day
+    /// they are reported separately as half days.
+    #[cfg(feature = "alloc")]
+    pub fn free_days_between(&self, from: NaiveDate, to: NaiveDate) -> FreeDays {
+        let holidays = self.holidays_between(from, to);
+        let (days, working_days): (Vec<_>, Vec<_>) = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .partition(|date| {
+                matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+                    || holidays
+                        .iter()
+                        .any(|(holiday_date, _)| holiday_date == date)
+            });
+        let half_days = working_days
             .into_iter()
-            .find(|holiday| holiday.date(date.year()) == Some(date))
+            .filter_map(|date| {
+                [Heiligabend, Silvester]
+                    .iter()
+                    .copied()
+                    .find(|holiday| holiday.date(date.year()) == Some(date))
+                    .map(|holiday| (date, holiday))
+            })
+            .collect();
+        FreeDays { days, half_days }
+    }
+
+    /// Returns the first public holiday strictly after the given date, rolling over into the following year if necessary.
+    ///
+    /// For dates before 1990 this is the first holiday of 1990.
+    pub fn next_holiday(&self, after: NaiveDate) -> Option<(NaiveDate, GermanHoliday)> {
+        let start_year = after.year().max(FIRST_YEAR);
+        (start_year..=start_year.saturating_add(1))
+            .flat_map(|year| self.iter_holiday_dates(year))
+            .find(|(date, _)| *date > after)
+    }
+
+    /// Returns the number of days from the given date until the next public holiday, see `next_holiday`.
+    pub fn days_until_next_holiday(&self, from: NaiveDate) -> Option<i64> {
+        self.next_holiday(from)
+            .map(|(date, _)| date.signed_duration_since(from).num_days())
+    }
+
+    /// Returns the next `n` public holidays strictly after the given date, spanning as many years as necessary.
+    #[cfg(feature = "alloc")]
+    pub fn next_n_holidays(&self, after: NaiveDate, n: usize) -> Vec<DatedHoliday> {
+        self.iter_holidays_from(after)
+            .skip_while(|(date, _)| *date == after)
+            .take(n)
+            .collect()
+    }
+
+    /// Returns a lazy iterator over all public holidays on or after the given date in chronological order.
+    ///
+    /// The iterator only ends at the last year supported by `chrono`.
+    pub fn iter_holidays_from(&self, start: NaiveDate) -> impl Iterator<Item = DatedHoliday> {
+        let region = *self;
+        (start.year().max(FIRST_YEAR)..=NaiveDate::MAX.year())
+            .flat_map(move |year| region.iter_holiday_dates(year))
+            .skip_while(move |(date, _)| *date < start)
+    }
+
+    /// Returns the last public holiday strictly before the given date, rolling over into the previous year if necessary.
+    ///
+    /// Always `None` for dates up to and including the first holiday of 1990.
+    pub fn previous_holiday(&self, before: NaiveDate) -> Option<(NaiveDate, GermanHoliday)> {
+        let start_year = before.year();
+        (start_year.saturating_sub(1)..=start_year)
+            .rev()
+            .flat_map(|year| self.iter_holiday_dates(year).rev())
+            .find(|(date, _)| *date < before)
+    }
+
+    /// Returns both the previous and the next public holiday relative to the given date,
+    /// as computed by `previous_holiday` and `next_holiday`.
+    pub fn surrounding_holidays(
+        &self,
+        date: NaiveDate,
+    ) -> (Option<DatedHoliday>, Option<DatedHoliday>) {
+        (self.previous_holiday(date), self.next_holiday(date))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl GermanRegion {
+    /// Checks if today, according to the given clock, is a public holiday.
+    ///
+    /// Use `SystemClock` for the local system clock.
+    pub fn is_today_holiday<C: Clock>(&self, clock: &C) -> bool {
+        self.todays_holiday(clock).is_some()
+    }
+
+    /// Checks if tomorrow, according to the given clock, is a public holiday.
+    pub fn is_tomorrow_holiday<C: Clock>(&self, clock: &C) -> bool {
+        clock
+            .today()
+            .succ_opt()
+            .is_some_and(|tomorrow| self.is_holiday(tomorrow))
+    }
+
+    /// Returns today's public holiday, according to the given clock.
+    pub fn todays_holiday<C: Clock>(&self, clock: &C) -> Option<GermanHoliday> {
+        self.holiday_from_date(clock.today())
+    }
+}
+
+#[cfg(feature = "tz")]
+impl GermanRegion {
+    /// Checks if the given instant falls on a public holiday,
+    /// based on the civil date in Europe/Berlin.
+    pub fn is_holiday_at<Tz: chrono::TimeZone>(&self, datetime: &chrono::DateTime<Tz>) -> bool {
+        self.holiday_at(datetime).is_some()
+    }
+
+    /// Returns the public holiday the given instant falls on,
+    /// based on the civil date in Europe/Berlin.
+    pub fn holiday_at<Tz: chrono::TimeZone>(
+        &self,
+        datetime: &chrono::DateTime<Tz>,
+    ) -> Option<GermanHoliday> {
+        let date = datetime
+            .with_timezone(&chrono_tz::Europe::Berlin)
+            .date_naive();
+        self.holiday_from_date(date)
     }
 }
 
-const BUNDESWEITE_FEIERTAGE: &'static [GermanHoliday] = &[
+/// Years for which all holidays can be calculated reliably.
+///
+/// Public holidays are only calculated since 1990 and the date of easter
+/// is only calculated up to the year 9999.
+/// Beyond this range, infallible queries return empty results or skip easter-dependent holidays.
+pub fn supported_year_range() -> RangeInclusive<i32> {
+    FIRST_YEAR..=9999
+}
+
+/// Maximum number of holidays a single region can have in one year,
+/// i.e. the length of the array returned by `GermanRegion::holiday_dates_in_year_array`.
+pub const MAX_HOLIDAYS_PER_YEAR: usize = GermanHoliday::ALL.len();
+
+#[cfg(any(feature = "alloc", feature = "chrono"))]
+fn check_year(year: i32) -> Result<(), HolidayError> {
+    if supported_year_range().contains(&year) {
+        Ok(())
+    } else {
+        Err(HolidayError::UnsupportedYear(year))
+    }
+}
+
+/// Adds `duration` to `date`, saturating at `NaiveDate::MIN` or `NaiveDate::MAX` depending on its sign.
+#[cfg(feature = "chrono")]
+fn saturating_add(date: NaiveDate, duration: Duration) -> NaiveDate {
+    date.checked_add_signed(duration)
+        .unwrap_or(if duration < Duration::zero() {
+            NaiveDate::MIN
+        } else {
+            NaiveDate::MAX
+        })
+}
+
+impl FromStr for GermanRegion {
+    type Err = ParseRegionError;
+
+    /// Parses a region from its ISO 3166-2 code (`DE-BY`), the code without country prefix (`BY`),
+    /// a common abbreviation (`NRW`), its name (`Thüringen` or `Thueringen`), its official name (`Freistaat Thüringen`)
+    /// or its slug (`thueringen`), ignoring case.
+    ///
+    /// `Bundesweit` is only parsed from its name, not from the country code `DE`.
+    fn from_str(input: &str) -> Result<Self, ParseRegionError> {
+        let normalized = normalize(input);
+        let mut without_country = normalized.clone();
+        let without_country = (without_country.next() == Some('d')
+            && without_country.next() == Some('e')
+            && without_country.clone().count() == 2)
+            .then_some(without_country);
+        for &region in GermanRegion::ALL_WITH_BUNDESWEIT {
+            let code = region.iso_code().get(3..).map(normalize);
+            if code.clone().is_some_and(|code| normalized.clone().eq(code))
+                || without_country
+                    .clone()
+                    .zip(code)
+                    .is_some_and(|(rest, code)| rest.eq(code))
+                || normalized.clone().eq(normalize(region.name()))
+                || normalized.clone().eq(normalize(region.official_name()))
+                || normalized.clone().eq(normalize(region.slug()))
+                || normalized.clone().eq(normalize(region.identifier()))
+            {
+                return Ok(region);
+            }
+        }
+        [
+            ("bawue", BadenWuerttemberg),
+            ("brb", Brandenburg),
+            ("nds", Niedersachsen),
+            ("nrw", NordrheinWestfalen),
+            ("rlp", RheinlandPfalz),
+            ("lsa", SachsenAnhalt),
+        ]
+        .iter()
+        .find(|(abbreviation, _)| normalized.clone().eq(abbreviation.chars()))
+        .map(|&(_, region)| region)
+        .ok_or(ParseRegionError)
+    }
+}
+
+/// Lowercases the input, transliterates umlauts and drops separators, without allocating.
+fn normalize(input: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    input
+        .trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !matches!(c, '-' | ' ' | '_'))
+        .flat_map(|c| {
+            let (base, suffix) = match c {
+                'ä' => ('a', Some('e')),
+                'ö' => ('o', Some('e')),
+                'ü' => ('u', Some('e')),
+                c => (c, None),
+            };
+            iter::once(base).chain(suffix)
+        })
+}
+
+/// Returns the public holidays of several regions in the given year, sorted by date,
+/// together with the regions in which each holiday applies.
+#[cfg(feature = "alloc")]
+pub(crate) fn holiday_ymds_of_regions(
+    regions: &[GermanRegion],
+    year: i32,
+) -> Vec<(Ymd, GermanHoliday, Vec<GermanRegion>)> {
+    let mut rows = Vec::<(Ymd, GermanHoliday, Vec<GermanRegion>)>::new();
+    for &region in regions {
+        for (date, holiday) in region.iter_holiday_ymds(year) {
+            match rows
+                .iter_mut()
+                .find(|(row_date, row_holiday, _)| *row_date == date && *row_holiday == holiday)
+            {
+                Some((_, _, regions)) => regions.push(region),
+                None => rows.push((date, holiday, vec![region])),
+            }
+        }
+    }
+    rows.sort_by_key(|&(date, _, _)| date);
+    rows
+}
+
+pub(crate) const FIRST_YEAR: i32 = 1990;
+
+pub(crate) const BUNDESWEITE_FEIERTAGE: &[GermanHoliday] = &[
     Neujahr,
     Karfreitag,
     Ostermontag,
@@ -172,18 +872,22 @@ const BUNDESWEITE_FEIERTAGE: &'static [GermanHoliday] = &[
     ZweiterWeihnachtsfeiertag,
 ];
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use crate::regions::GermanHoliday::*;
     use crate::regions::GermanRegion;
     use crate::regions::GermanRegion::*;
+    use crate::regions::{
+        supported_year_range, HolidayError, HolidayType, Language, ParseRegionError,
+    };
     use crate::DateExt;
+    use crate::FixedClock;
     use chrono::NaiveDate;
     use proptest::prelude::*;
 
     #[test]
     fn singular_example_holiday() {
-        let date = NaiveDate::from_ymd(2018, 1, 1);
+        let date = NaiveDate::from_ymd_opt(2018, 1, 1).unwrap();
         assert!(date.is_public_holiday_in(Bayern));
         assert_eq!(Some(Neujahr), date.public_holiday_in(Bayern));
     }
@@ -208,7 +912,124 @@ mod tests {
         assert_eq!(11, number_holidays(SachsenAnhalt));
         assert_eq!(10, number_holidays(SchleswigHolstein));
         assert_eq!(11, number_holidays(Thueringen));
+        assert_eq!(9, number_holidays(Bundesweit));
+    }
+    }
+
+    #[test]
+    fn bundesweit_only_has_nationwide_holidays() {
+        assert!(Bundesweit
+            .iter_holidays_in_year(2024)
+            .eq(super::BUNDESWEITE_FEIERTAGE.iter().copied()));
+        assert_eq!(
+            Bundesweit
+                .diff(Brandenburg, 2024)
+                .only_other
+                .iter()
+                .collect::<Vec<_>>(),
+            [Reformationstag]
+        );
+        assert!(Bundesweit.holidays_in_year(1994).contains(&BussUndBettag));
+        assert!(!Bundesweit.holidays_in_year(1995).contains(&BussUndBettag));
+        assert!(!Bundesweit.holidays_in_year(2025).contains(&TagDerBefreiung));
+        assert!(Bundesweit.holidays_in_year(2017).contains(&Reformationstag));
+    }
+
+    #[test]
+    fn holiday_type_depends_on_region() {
+        assert_eq!(HolidayType::Public, Bayern.holiday_type(Fronleichnam, 2019));
+        assert_eq!(
+            HolidayType::Observance,
+            Berlin.holiday_type(Fronleichnam, 2019)
+        );
+        assert_eq!(HolidayType::Observance, Berlin.holiday_type(Neujahr, 1989));
+    }
+
+    proptest! {
+    #[test]
+    fn iter_holiday_dates_matches_holidays_in_year(year in 1990i32..2100) {
+        for &region in GermanRegion::ALL {
+            let holiday_dates: Vec<_> = region.iter_holiday_dates(year).collect();
+            assert_eq!(region.holidays_in_year(year).len(), holiday_dates.len());
+            assert!(holiday_dates.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        }
+    }
+    }
+
+    proptest! {
+    #[test]
+    fn holiday_dates_in_year_array_matches_holiday_dates_in_year(year in 1900i32..2100) {
+        for &region in GermanRegion::ALL {
+            let (holiday_dates, len) = region.holiday_dates_in_year_array(year);
+            assert!(holiday_dates[len..].iter().all(Option::is_none));
+            let holiday_dates: Vec<_> = holiday_dates.iter().flatten().copied().collect();
+            assert_eq!(region.holiday_dates_in_year(year), holiday_dates);
+        }
+    }
+    }
+
+    #[test]
+    fn holiday_date_map_agrees_with_holiday_from_date() {
+        let map = Bayern.holiday_date_map(2008);
+        assert_eq!(12, map.len());
+        for (date, holiday) in map {
+            assert_eq!(Some(holiday), Bayern.holiday_from_date(date));
+        }
+    }
+
+    #[test]
+    fn buss_und_bettag_nationwide_before_1995() {
+        for &region in GermanRegion::ALL {
+            assert!(region.holidays_in_year(1990).contains(&BussUndBettag));
+            assert!(region.holidays_in_year(1994).contains(&BussUndBettag));
+            assert_eq!(
+                region == Sachsen,
+                region.holidays_in_year(1995).contains(&BussUndBettag)
+            );
+        }
+        assert_eq!(
+            Some(BussUndBettag),
+            NaiveDate::from_ymd_opt(1994, 11, 16)
+                .unwrap()
+                .public_holiday_in(Bayern)
+        );
+    }
+
+    #[test]
+    fn siebzehnter_juni_in_west_germany_1990() {
+        let date = NaiveDate::from_ymd_opt(1990, 6, 17).unwrap();
+        assert_eq!(Some(SiebzehnterJuni), date.public_holiday_in(Hamburg));
+        assert_eq!(None, date.public_holiday_in(Sachsen));
+        assert_eq!(None, date.public_holiday_in(Berlin));
+        assert!(!Hamburg.holidays_in_year(1991).contains(&SiebzehnterJuni));
+    }
+
+    #[test]
+    fn eastern_states_in_1990() {
+        let date = NaiveDate::from_ymd_opt(1990, 1, 6).unwrap();
+        assert_eq!(None, date.public_holiday_in(SachsenAnhalt));
+        let date = NaiveDate::from_ymd_opt(1991, 1, 6).unwrap();
+        assert_eq!(
+            Some(HeiligeDreiKoenige),
+            date.public_holiday_in(SachsenAnhalt)
+        );
+        let date = NaiveDate::from_ymd_opt(1990, 10, 31).unwrap();
+        assert_eq!(None, date.public_holiday_in(Thueringen));
     }
+
+    #[test]
+    fn tag_der_befreiung_in_berlin_2020_and_2025() {
+        let date = NaiveDate::from_ymd_opt(2020, 5, 8).unwrap();
+        assert!(Berlin.is_holiday(date));
+        assert_eq!(Some(TagDerBefreiung), date.public_holiday_in(Berlin));
+        assert!(!Brandenburg.is_holiday(date));
+        assert!(!Berlin.is_holiday(NaiveDate::from_ymd_opt(2021, 5, 8).unwrap()));
+        assert_eq!(
+            Some(TagDerBefreiung),
+            NaiveDate::from_ymd_opt(2025, 5, 8)
+                .unwrap()
+                .public_holiday_in(Berlin)
+        );
     }
 
     #[test]
@@ -216,20 +1037,371 @@ mod tests {
         assert!(!Berlin.holidays_in_year(2018).contains(&Frauentag));
         assert_eq!(
             None,
-            NaiveDate::from_ymd(2018, 3, 8).public_holiday_in(Berlin)
+            NaiveDate::from_ymd_opt(2018, 3, 8)
+                .unwrap()
+                .public_holiday_in(Berlin)
         );
         assert!(Berlin.holidays_in_year(2019).contains(&Frauentag));
         assert_eq!(
             Some(Frauentag),
-            NaiveDate::from_ymd(2019, 3, 8).public_holiday_in(Berlin)
+            NaiveDate::from_ymd_opt(2019, 3, 8)
+                .unwrap()
+                .public_holiday_in(Berlin)
+        );
+    }
+
+    #[test]
+    fn next_holiday_rolls_over_into_following_year() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            Some((date(2019, 6, 20), Fronleichnam)),
+            Bayern.next_holiday(date(2019, 6, 10))
+        );
+        assert_eq!(
+            Some((date(2020, 1, 1), Neujahr)),
+            Berlin.next_holiday(date(2019, 12, 26))
+        );
+        assert_eq!(
+            Some((date(1990, 1, 1), Neujahr)),
+            Berlin.next_holiday(date(1985, 7, 1))
+        );
+    }
+
+    #[test]
+    fn days_until_next_holiday() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(Some(1), Berlin.days_until_next_holiday(date(2019, 12, 25)));
+        assert_eq!(Some(6), Berlin.days_until_next_holiday(date(2019, 12, 26)));
+        assert_eq!(Some(1), Berlin.days_until_next_holiday(date(1989, 12, 31)));
+    }
+
+    #[test]
+    fn next_n_holidays_spans_years() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let holidays = Hamburg.next_n_holidays(date(2019, 10, 31), 12);
+        assert_eq!(12, holidays.len());
+        assert_eq!((date(2019, 12, 25), ErsterWeihnachtsfeiertag), holidays[0]);
+        assert_eq!((date(2020, 10, 31), Reformationstag), holidays[9]);
+        assert_eq!(
+            (date(2020, 12, 26), ZweiterWeihnachtsfeiertag),
+            holidays[11]
+        );
+        assert!(Hamburg.next_n_holidays(date(2019, 10, 31), 0).is_empty());
+    }
+
+    #[test]
+    fn iter_holidays_from_includes_start() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut holidays = Berlin.iter_holidays_from(date(2019, 12, 26));
+        assert_eq!(
+            Some((date(2019, 12, 26), ZweiterWeihnachtsfeiertag)),
+            holidays.next()
+        );
+        assert_eq!(Some((date(2020, 1, 1), Neujahr)), holidays.next());
+        assert_eq!(Some((date(2020, 3, 8), Frauentag)), holidays.next());
+    }
+
+    proptest! {
+    #[test]
+    fn iter_holidays_from_is_chronological(days in 0i64..20000) {
+        let start = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap() + chrono::Duration::days(days);
+        let holidays: Vec<_> = Thueringen.iter_holidays_from(start).take(30).collect();
+        assert_eq!(30, holidays.len());
+        assert!(start <= holidays[0].0);
+        assert!(holidays.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    }
+    }
+
+    #[test]
+    fn previous_holiday_rolls_over_into_previous_year() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            Some((date(2019, 6, 10), Pfingstmontag)),
+            Bayern.previous_holiday(date(2019, 6, 20))
+        );
+        assert_eq!(
+            Some((date(2019, 12, 26), ZweiterWeihnachtsfeiertag)),
+            Berlin.previous_holiday(date(2020, 1, 1))
+        );
+        assert_eq!(None, Berlin.previous_holiday(date(1990, 1, 1)));
+    }
+
+    #[test]
+    fn describe_day_lists_observances() {
+        let date = NaiveDate::from_ymd_opt(2019, 4, 21).unwrap();
+        let description = Bayern.describe_day(date, Language::German);
+        assert_eq!(None, description.public_holiday);
+        assert_eq!(vec![Ostersonntag], description.observances);
+
+        let date = NaiveDate::from_ymd_opt(2019, 8, 15).unwrap();
+        let description = Berlin.describe_day(date, Language::German);
+        assert_eq!(None, description.public_holiday);
+        assert_eq!(vec![MariaeHimmelfahrt], description.observances);
+        let description = Bayern.describe_day(date, Language::German);
+        assert_eq!(Some(MariaeHimmelfahrt), description.public_holiday);
+        assert!(description.observances.is_empty());
+    }
+
+    #[test]
+    fn describe_day_skips_historical_holidays() {
+        for (month, day) in [(10, 7), (6, 17)] {
+            let date = NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+            let description = Bayern.describe_day(date, Language::German);
+            assert_eq!(None, description.public_holiday);
+            assert!(description.observances.is_empty());
+        }
+        let date = NaiveDate::from_ymd_opt(2025, 5, 8).unwrap();
+        assert_eq!(
+            vec![TagDerBefreiung],
+            Bayern.describe_day(date, Language::German).observances
+        );
+    }
+
+    #[test]
+    fn describe_day_bridge_day_and_language() {
+        // Tag der Deutschen Einheit 2024 was a thursday
+        let friday = NaiveDate::from_ymd_opt(2024, 10, 4).unwrap();
+        assert!(Berlin.is_bridge_day(friday));
+        assert!(!Berlin.is_bridge_day(NaiveDate::from_ymd_opt(2024, 10, 3).unwrap()));
+        assert!(!Berlin.is_bridge_day(NaiveDate::from_ymd_opt(2024, 10, 2).unwrap()));
+        // Fronleichnam 2024 was a thursday
+        let friday = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+        assert!(Bayern.is_bridge_day(friday));
+        assert!(!Berlin.is_bridge_day(friday));
+
+        let description = Bayern.describe_day(friday, Language::English);
+        assert!(description.bridge_day);
+        assert_eq!("2024-05-31: bridge day", description.to_string());
+        assert_eq!(
+            "2024-05-31: kein Feiertag",
+            Berlin.describe_day(friday, Language::German).to_string()
+        );
+        let date = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+        assert_eq!(
+            "2024-10-03: Tag der Deutschen Einheit (gesetzlicher Feiertag)",
+            Bayern.describe_day(date, Language::German).to_string()
+        );
+        let date = NaiveDate::from_ymd_opt(2024, 10, 2).unwrap();
+        assert_eq!(
+            "2024-10-02: no holiday",
+            Bayern.describe_day(date, Language::English).to_string()
+        );
+    }
+
+    proptest! {
+    #[test]
+    fn surrounding_holidays_match_previous_and_next(days in 0i64..20000) {
+        let date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap() + chrono::Duration::days(days);
+        assert_eq!(
+            (Sachsen.previous_holiday(date), Sachsen.next_holiday(date)),
+            Sachsen.surrounding_holidays(date)
+        );
+    }
+    }
+
+    #[test]
+    fn free_days_across_year_boundary() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            vec![
+                (date(2019, 12, 25), ErsterWeihnachtsfeiertag),
+                (date(2019, 12, 26), ZweiterWeihnachtsfeiertag),
+                (date(2020, 1, 1), Neujahr),
+                (date(2020, 1, 6), HeiligeDreiKoenige),
+            ],
+            Bayern.holidays_between(date(2019, 12, 20), date(2020, 1, 7))
+        );
+        assert_eq!(
+            vec![
+                date(2019, 12, 21),
+                date(2019, 12, 22),
+                date(2019, 12, 25),
+                date(2019, 12, 26),
+                date(2019, 12, 28),
+                date(2019, 12, 29),
+                date(2020, 1, 1),
+                date(2020, 1, 4),
+                date(2020, 1, 5),
+                date(2020, 1, 6),
+            ],
+            Bayern
+                .free_days_between(date(2019, 12, 20), date(2020, 1, 7))
+                .days
+        );
+        assert_eq!(
+            vec![
+                (date(2019, 12, 24), Heiligabend),
+                (date(2019, 12, 31), Silvester)
+            ],
+            Bayern
+                .free_days_between(date(2019, 12, 20), date(2020, 1, 7))
+                .half_days
+        );
+        // Heiligabend and Silvester 2022 were saturdays
+        assert!(Bayern
+            .free_days_between(date(2022, 12, 24), date(2022, 12, 31))
+            .half_days
+            .is_empty());
+    }
+
+    #[test]
+    fn upcoming_holidays_within_horizon() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            vec![
+                (date(2019, 12, 25), ErsterWeihnachtsfeiertag),
+                (date(2019, 12, 26), ZweiterWeihnachtsfeiertag),
+                (date(2020, 1, 1), Neujahr),
+            ],
+            Berlin.upcoming_holidays(date(2019, 12, 25), chrono::Duration::days(7))
+        );
+        assert!(Berlin
+            .upcoming_holidays(date(2019, 12, 25), chrono::Duration::days(-7))
+            .is_empty());
+    }
+
+    #[test]
+    fn reminders_across_year_boundary() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let reminders: Vec<_> = Berlin
+            .reminders(
+                date(2019, 12, 1),
+                date(2019, 12, 31),
+                chrono::Duration::days(7),
+            )
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    date(2019, 12, 18),
+                    date(2019, 12, 25),
+                    ErsterWeihnachtsfeiertag
+                ),
+                (
+                    date(2019, 12, 19),
+                    date(2019, 12, 26),
+                    ZweiterWeihnachtsfeiertag
+                ),
+                (date(2019, 12, 25), date(2020, 1, 1), Neujahr),
+            ],
+            reminders
+        );
+    }
+
+    #[test]
+    fn reminders_and_upcoming_holidays_do_not_overflow() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let far_past = chrono::Duration::days(-100_000_000);
+        assert_eq!(
+            0,
+            Berlin
+                .reminders(date(2019, 12, 1), date(2019, 12, 31), far_past)
+                .count()
+        );
+        assert!(Berlin
+            .upcoming_holidays(date(2019, 12, 1), far_past)
+            .is_empty());
+        let reminders = Berlin.reminders(
+            NaiveDate::MAX - chrono::Duration::days(7),
+            NaiveDate::MAX,
+            chrono::Duration::days(-7),
+        );
+        assert!(reminders
+            .take(3)
+            .all(|(reminder, holiday_date, _)| reminder > holiday_date));
+    }
+
+    #[test]
+    fn today_helpers_use_clock() {
+        let clock = FixedClock(NaiveDate::from_ymd_opt(2019, 12, 25).unwrap());
+        assert!(Berlin.is_today_holiday(&clock));
+        assert!(Berlin.is_tomorrow_holiday(&clock));
+        assert_eq!(
+            Some(ErsterWeihnachtsfeiertag),
+            Berlin.todays_holiday(&clock)
+        );
+        let clock = FixedClock(NaiveDate::from_ymd_opt(2019, 12, 27).unwrap());
+        assert!(!Berlin.is_today_holiday(&clock));
+        assert!(!Berlin.is_tomorrow_holiday(&clock));
+    }
+
+    #[cfg(feature = "tz")]
+    #[test]
+    fn is_holiday_at_uses_berlin_civil_time() {
+        use chrono::{TimeZone, Utc};
+        let new_years_eve = Utc.with_ymd_and_hms(2019, 12, 31, 23, 30, 0).unwrap();
+        assert!(Berlin.is_holiday_at(&new_years_eve));
+        assert!(!Berlin.is_holiday_at(&(new_years_eve - chrono::Duration::hours(1))));
+        let summer = Utc.with_ymd_and_hms(2019, 10, 2, 22, 30, 0).unwrap();
+        assert_eq!(Some(TagDerDeutschenEinheit), Berlin.holiday_at(&summer));
+    }
+
+    #[test]
+    fn try_variants_reject_years_before_1990() {
+        assert_eq!(
+            Err(HolidayError::UnsupportedYear(94)),
+            Bayern.try_holidays_in_year(94)
         );
+        assert_eq!(
+            Ok(Bayern.holidays_in_year(1990)),
+            Bayern.try_holidays_in_year(1990)
+        );
+        assert_eq!(
+            Err(HolidayError::UnsupportedYear(1989)),
+            Bayern.try_holiday_from_date(NaiveDate::from_ymd_opt(1989, 12, 31).unwrap())
+        );
+        assert_eq!(
+            Ok(Some(Neujahr)),
+            Bayern.try_holiday_from_date(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap())
+        );
+        assert_eq!(
+            Err(HolidayError::UnsupportedYear(10000)),
+            Bayern.try_holidays_in_year(10000)
+        );
+        assert!(Bayern
+            .holiday_dates_in_year(*supported_year_range().end())
+            .iter()
+            .any(|(_, holiday)| *holiday == Karfreitag));
     }
 
     proptest! {
     #[test]
-    fn only_provide_holidays_after_1995(year in -2999i32..1995) {
+    fn only_provide_holidays_after_1990(year in -2999i32..1990) {
         assert!(BadenWuerttemberg.holidays_in_year(year).is_empty());
     }
     }
 
+    #[test]
+    fn identifier_matches_debug() {
+        for region in GermanRegion::ALL_WITH_BUNDESWEIT {
+            assert_eq!(region.identifier(), format!("{:?}", region));
+        }
+    }
+
+    #[test]
+    fn parse_region() {
+        for &region in GermanRegion::ALL {
+            assert_eq!(region.iso_code().parse(), Ok(region));
+            assert_eq!(region.iso_code()[3..].parse(), Ok(region));
+            assert_eq!(region.name().parse(), Ok(region));
+            assert_eq!(region.official_name().parse(), Ok(region));
+            assert_eq!(region.slug().parse(), Ok(region));
+            assert!(region
+                .slug()
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte == b'-'));
+            assert_eq!(format!("{:?}", region).parse(), Ok(region));
+        }
+        assert_eq!("NRW".parse(), Ok(NordrheinWestfalen));
+        assert_eq!("de-by".parse(), Ok(Bayern));
+        assert_eq!("thueringen".parse(), Ok(Thueringen));
+        assert_eq!("bundesweit".parse(), Ok(Bundesweit));
+        assert_eq!("Freistaat Thüringen".parse(), Ok(Thueringen));
+        assert_eq!(NordrheinWestfalen.english_name(), "North Rhine-Westphalia");
+        assert_eq!("freie-und-hansestadt-hamburg".parse(), Ok(Hamburg));
+        assert_eq!("Sachsen Anhalt".parse(), Ok(SachsenAnhalt));
+        assert_eq!("M-V".parse(), Ok(MechlenburgVorpommern));
+        assert_eq!("Bavaria".parse::<GermanRegion>(), Err(ParseRegionError));
+        assert_eq!("DE".parse::<GermanRegion>(), Err(ParseRegionError));
+    }
 }
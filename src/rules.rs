@@ -0,0 +1,261 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::holidays::GermanHoliday;
+use crate::holidays::GermanHoliday::*;
+#[cfg(feature = "alloc")]
+use crate::one_off::ONE_OFF_HOLIDAYS;
+use crate::regions::GermanRegion;
+use crate::regions::GermanRegion::*;
+#[cfg(feature = "alloc")]
+use crate::regions::FIRST_YEAR;
+
+/// Version of the holiday data in this crate, changed whenever rules are added or corrected.
+pub fn data_version() -> &'static str {
+    "2025.2"
+}
+
+/// Kind of a `LegalChange`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChangeKind {
+    /// The holiday applies from this year on.
+    Introduced,
+    /// The holiday no longer applies from this year on.
+    Abolished,
+    /// The holiday applies only in this year.
+    OneOff,
+}
+
+/// A change of the public holidays in a region, see `legal_changes`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LegalChange {
+    pub year: i32,
+    pub region: GermanRegion,
+    pub holiday: GermanHoliday,
+    pub kind: ChangeKind,
+}
+
+/// Returns all changes of public holidays since 1990 included in the data of this crate, sorted by year.
+#[cfg(feature = "alloc")]
+pub fn legal_changes() -> Vec<LegalChange> {
+    let mut changes = Vec::new();
+    for rule in HOLIDAY_RULES {
+        if let Some(year) = rule.valid_from.filter(|&year| year > FIRST_YEAR) {
+            changes.push(LegalChange {
+                year,
+                region: rule.region,
+                holiday: rule.holiday,
+                kind: ChangeKind::Introduced,
+            });
+        }
+        if let Some(year) = rule.valid_to {
+            changes.push(LegalChange {
+                year: year + 1,
+                region: rule.region,
+                holiday: rule.holiday,
+                kind: ChangeKind::Abolished,
+            });
+        }
+    }
+    changes.extend(ONE_OFF_HOLIDAYS.iter().map(|one_off| LegalChange {
+        year: one_off.year,
+        region: one_off.region,
+        holiday: one_off.holiday,
+        kind: ChangeKind::OneOff,
+    }));
+    changes.sort_by_key(|change| change.year);
+    changes
+}
+
+/// A regional public holiday together with the years in which it applies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HolidayRule {
+    pub region: GermanRegion,
+    pub holiday: GermanHoliday,
+    /// First year in which the holiday applies, `None` if it has applied since before 1990.
+    pub valid_from: Option<i32>,
+    /// Last year in which the holiday applies, `None` if it still applies.
+    pub valid_to: Option<i32>,
+}
+
+impl HolidayRule {
+    pub(crate) const fn always(region: GermanRegion, holiday: GermanHoliday) -> Self {
+        Self {
+            region,
+            holiday,
+            valid_from: None,
+            valid_to: None,
+        }
+    }
+
+    const fn since(region: GermanRegion, holiday: GermanHoliday, year: i32) -> Self {
+        Self {
+            region,
+            holiday,
+            valid_from: Some(year),
+            valid_to: None,
+        }
+    }
+
+    pub(crate) const fn between(
+        region: GermanRegion,
+        holiday: GermanHoliday,
+        from: i32,
+        to: i32,
+    ) -> Self {
+        Self {
+            region,
+            holiday,
+            valid_from: Some(from),
+            valid_to: Some(to),
+        }
+    }
+
+    /// True if the rule applies in the given year.
+    pub fn applies_in(&self, year: i32) -> bool {
+        self.valid_from.is_none_or(|from| year >= from) && self.valid_to.is_none_or(|to| year <= to)
+    }
+}
+
+impl GermanRegion {
+    /// Returns all rules for regional public holidays in this region,
+    /// including those which no longer apply.
+    ///
+    /// Holidays which apply in all of Germany and one-off holidays are not included.
+    pub fn rules(&self) -> impl Iterator<Item = &'static HolidayRule> {
+        let region = *self;
+        HOLIDAY_RULES
+            .iter()
+            .filter(move |rule| rule.region == region)
+    }
+}
+
+/// All rules for regional public holidays, see `GermanRegion::rules`.
+///
+/// The regional holidays of the eastern states apply from 1991 on, the first full year
+/// after reunification on 3 October 1990.
+pub static HOLIDAY_RULES: &[HolidayRule] = &[
+    HolidayRule::always(BadenWuerttemberg, HeiligeDreiKoenige),
+    HolidayRule::always(BadenWuerttemberg, Fronleichnam),
+    HolidayRule::always(BadenWuerttemberg, Allerheiligen),
+    HolidayRule::always(Bayern, HeiligeDreiKoenige),
+    HolidayRule::always(Bayern, Fronleichnam),
+    HolidayRule::always(Bayern, MariaeHimmelfahrt),
+    HolidayRule::always(Bayern, Allerheiligen),
+    HolidayRule::since(Berlin, Frauentag, 2019),
+    HolidayRule::since(Brandenburg, Reformationstag, 1991),
+    HolidayRule::since(Bremen, Reformationstag, 2017),
+    HolidayRule::since(Hamburg, Reformationstag, 2017),
+    HolidayRule::always(Hessen, Fronleichnam),
+    HolidayRule::since(MechlenburgVorpommern, Frauentag, 2023),
+    HolidayRule::since(MechlenburgVorpommern, Reformationstag, 1991),
+    HolidayRule::since(Niedersachsen, Reformationstag, 2017),
+    HolidayRule::always(NordrheinWestfalen, Fronleichnam),
+    HolidayRule::always(NordrheinWestfalen, Allerheiligen),
+    HolidayRule::always(RheinlandPfalz, Fronleichnam),
+    HolidayRule::always(RheinlandPfalz, Allerheiligen),
+    HolidayRule::always(Saarland, Fronleichnam),
+    HolidayRule::always(Saarland, MariaeHimmelfahrt),
+    HolidayRule::always(Saarland, Allerheiligen),
+    HolidayRule::since(Sachsen, Reformationstag, 1991),
+    HolidayRule::always(Sachsen, BussUndBettag),
+    HolidayRule::since(SachsenAnhalt, HeiligeDreiKoenige, 1991),
+    HolidayRule::since(SachsenAnhalt, Reformationstag, 1991),
+    HolidayRule::since(SchleswigHolstein, Reformationstag, 2017),
+    HolidayRule::since(Thueringen, Weltkindertag, 2019),
+    HolidayRule::since(Thueringen, Reformationstag, 1991),
+    // Abolished everywhere except Sachsen from 1995 on by Art. 68 PflegeVG:
+    // https://www.bgbl.de/xaver/bgbl/start.xav?startbk=Bundesanzeiger_BGBl&jumpTo=bgbl194s1014.pdf
+    HolidayRule::between(BadenWuerttemberg, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Bayern, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Berlin, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Brandenburg, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Bremen, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Hamburg, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Hessen, BussUndBettag, 1990, 1994),
+    HolidayRule::between(MechlenburgVorpommern, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Niedersachsen, BussUndBettag, 1990, 1994),
+    HolidayRule::between(NordrheinWestfalen, BussUndBettag, 1990, 1994),
+    HolidayRule::between(RheinlandPfalz, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Saarland, BussUndBettag, 1990, 1994),
+    HolidayRule::between(SachsenAnhalt, BussUndBettag, 1990, 1994),
+    HolidayRule::between(SchleswigHolstein, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Thueringen, BussUndBettag, 1990, 1994),
+    HolidayRule::between(Bundesweit, BussUndBettag, 1990, 1994),
+    // Observed in West Germany for the last time before reunification on 3 October 1990.
+    // Since 1990 `Berlin` covers the whole city, where it was only observed in West Berlin.
+    HolidayRule::between(BadenWuerttemberg, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Bayern, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Bremen, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Hamburg, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Hessen, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Niedersachsen, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(NordrheinWestfalen, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(RheinlandPfalz, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(Saarland, SiebzehnterJuni, 1990, 1990),
+    HolidayRule::between(SchleswigHolstein, SiebzehnterJuni, 1990, 1990),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_changes_in_mecklenburg_vorpommern() {
+        let changes = MechlenburgVorpommern
+            .rules()
+            .filter(|rule| rule.valid_from.is_some() || rule.valid_to.is_some())
+            .map(|rule| (rule.holiday, rule.valid_from, rule.valid_to))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                (Frauentag, Some(2023), None),
+                (Reformationstag, Some(1991), None),
+                (BussUndBettag, Some(1990), Some(1994)),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn legal_changes_are_sorted() {
+        let changes = legal_changes();
+        assert!(changes.windows(2).all(|w| w[0].year <= w[1].year));
+        assert!(changes.contains(&LegalChange {
+            year: 2023,
+            region: MechlenburgVorpommern,
+            holiday: Frauentag,
+            kind: ChangeKind::Introduced,
+        }));
+        assert!(changes.contains(&LegalChange {
+            year: 1995,
+            region: Bayern,
+            holiday: BussUndBettag,
+            kind: ChangeKind::Abolished,
+        }));
+        assert!(changes.contains(&LegalChange {
+            year: 2025,
+            region: Berlin,
+            holiday: TagDerBefreiung,
+            kind: ChangeKind::OneOff,
+        }));
+    }
+
+    #[test]
+    fn applies_in() {
+        let rule = HolidayRule::between(Bayern, BussUndBettag, 1990, 1994);
+        assert!(!rule.applies_in(1989));
+        assert!(rule.applies_in(1990));
+        assert!(rule.applies_in(1994));
+        assert!(!rule.applies_in(1995));
+        assert!(HolidayRule::always(Bayern, Fronleichnam).applies_in(1990));
+    }
+}
@@ -0,0 +1,177 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::clock::{Clock, SystemClock};
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// A holiday in the JSON responses of the `router`.
+#[derive(Serialize)]
+struct HolidayResponse {
+    date: String,
+    region: &'static str,
+    holiday: GermanHoliday,
+    name: &'static str,
+}
+
+impl HolidayResponse {
+    fn new(date: NaiveDate, region: GermanRegion, holiday: GermanHoliday) -> Self {
+        Self {
+            date: date.to_string(),
+            region: region.iso_code(),
+            holiday,
+            name: holiday.description(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct IsHolidayResponse {
+    is_holiday: bool,
+    holiday: Option<HolidayResponse>,
+}
+
+type ErrorResponse = (StatusCode, String);
+
+/// Returns an axum router serving public holidays as JSON:
+///
+/// * `GET /holidays/{region}/{year}`: all public holidays of a region in a year
+/// * `GET /is-holiday/{region}/{date}`: whether a date (`YYYY-MM-DD`) is a public holiday
+/// * `GET /next/{region}`: the next public holiday of a region, starting today
+///
+/// Regions are parsed with `GermanRegion::from_str`, so `DE-BY`, `BY` and `Bayern` are all accepted.
+/// Invalid input is answered with `400 Bad Request`.
+///
+/// The router can be served directly or nested into an existing application.
+pub fn router() -> Router {
+    router_with_clock(SystemClock)
+}
+
+/// Like `router`, but with a custom `Clock` determining "today" for `/next/{region}`.
+pub fn router_with_clock<C: Clock + Clone + Send + Sync + 'static>(clock: C) -> Router {
+    Router::new()
+        .route("/holidays/{region}/{year}", get(holidays))
+        .route("/is-holiday/{region}/{date}", get(is_holiday))
+        .route("/next/{region}", get(next::<C>))
+        .with_state(clock)
+}
+
+async fn holidays(
+    Path((region, year)): Path<(String, String)>,
+) -> Result<Json<Vec<HolidayResponse>>, ErrorResponse> {
+    let region = parse_region(&region)?;
+    let year = year
+        .parse()
+        .map_err(|_| bad_request(format!("invalid year: {}", year)))?;
+    region
+        .try_holidays_in_year(year)
+        .map_err(|e| bad_request(e.to_string()))?;
+    Ok(Json(
+        region
+            .iter_holiday_dates(year)
+            .map(|(date, holiday)| HolidayResponse::new(date, region, holiday))
+            .collect(),
+    ))
+}
+
+async fn is_holiday(
+    Path((region, date)): Path<(String, String)>,
+) -> Result<Json<IsHolidayResponse>, ErrorResponse> {
+    let region = parse_region(&region)?;
+    let date = date
+        .parse()
+        .map_err(|_| bad_request(format!("invalid date, expected YYYY-MM-DD: {}", date)))?;
+    let holiday = region
+        .try_holiday_from_date(date)
+        .map_err(|e| bad_request(e.to_string()))?;
+    Ok(Json(IsHolidayResponse {
+        is_holiday: holiday.is_some(),
+        holiday: holiday.map(|holiday| HolidayResponse::new(date, region, holiday)),
+    }))
+}
+
+async fn next<C: Clock>(
+    State(clock): State<C>,
+    Path(region): Path<String>,
+) -> Result<Json<Option<HolidayResponse>>, ErrorResponse> {
+    let region = parse_region(&region)?;
+    Ok(Json(region.iter_holidays_from(clock.today()).next().map(
+        |(date, holiday)| HolidayResponse::new(date, region, holiday),
+    )))
+}
+
+fn parse_region(input: &str) -> Result<GermanRegion, ErrorResponse> {
+    input
+        .parse()
+        .map_err(|_| bad_request(format!("unknown region: {}", input)))
+}
+
+fn bad_request(message: String) -> ErrorResponse {
+    (StatusCode::BAD_REQUEST, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn get(uri: &str) -> (StatusCode, String) {
+        let clock = FixedClock(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap());
+        let response = router_with_clock(clock)
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn holidays() {
+        let (status, body) = get("/holidays/DE-BE/2025").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.starts_with(
+            r#"[{"date":"2025-01-01","region":"DE-BE","holiday":"Neujahr","name":"Neujahr"},"#
+        ));
+        assert_eq!(body.matches("\"date\"").count(), 11);
+        assert_eq!(
+            get("/holidays/Bavaria/2025").await.0,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(get("/holidays/BY/1989").await.0, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn is_holiday() {
+        let (status, body) = get("/is-holiday/nrw/2024-11-01").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            r#"{"is_holiday":true,"holiday":{"date":"2024-11-01","region":"DE-NW","holiday":"Allerheiligen","name":"Allerheiligen"}}"#
+        );
+        assert_eq!(
+            get("/is-holiday/Berlin/2024-11-01").await.1,
+            r#"{"is_holiday":false,"holiday":null}"#
+        );
+        assert_eq!(
+            get("/is-holiday/Berlin/01.11.2024").await.0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn next() {
+        let (status, body) = get("/next/Hessen").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""date":"2024-12-25""#));
+    }
+}
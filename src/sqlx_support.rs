@@ -0,0 +1,120 @@
+use sqlx::decode::Decode;
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Type};
+
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+// Both are stored as text: regions as their ISO 3166-2 code, holidays as the name of their variant,
+// matching the `diesel` feature.
+
+impl<DB: Database> Type<DB> for GermanRegion
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for GermanRegion
+where
+    &'q str: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.iso_code().encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for GermanRegion
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let code = <&str as Decode<DB>>::decode(value)?;
+        GermanRegion::ALL_WITH_BUNDESWEIT
+            .iter()
+            .copied()
+            .find(|region| region.iso_code() == code)
+            .ok_or_else(|| format!("unknown region code: {}", code).into())
+    }
+}
+
+impl<DB: Database> Type<DB> for GermanHoliday
+where
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for GermanHoliday
+where
+    &'q str: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.identifier().encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for GermanHoliday
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let identifier = <&str as Decode<DB>>::decode(value)?;
+        GermanHoliday::ALL
+            .iter()
+            .copied()
+            .find(|holiday| holiday.identifier() == identifier)
+            .ok_or_else(|| format!("unknown holiday: {}", identifier).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::{Connection, SqliteConnection};
+
+    #[tokio::test]
+    async fn sqlite_roundtrip() {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        let region: GermanRegion = sqlx::query_scalar("SELECT ?")
+            .bind(GermanRegion::Bayern)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(region, GermanRegion::Bayern);
+        let code: String = sqlx::query_scalar("SELECT ?")
+            .bind(GermanRegion::Bayern)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(code, "DE-BY");
+        let holiday: GermanHoliday = sqlx::query_scalar("SELECT 'Fronleichnam'")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(holiday, GermanHoliday::Fronleichnam);
+        assert!(sqlx::query_scalar::<_, GermanRegion>("SELECT 'Bayern'")
+            .fetch_one(&mut conn)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! Generators for property tests of code using this crate.
+//!
+//! `GermanRegion` and `GermanHoliday` implement `proptest::arbitrary::Arbitrary` and `arbitrary::Arbitrary`,
+//! this module additionally provides generators for dates within `supported_year_range()`.
+use arbitrary::Unstructured;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use proptest::arbitrary::Arbitrary;
+use proptest::sample::{select, Select};
+use proptest::strategy::Strategy;
+
+use crate::calendar_date::CalendarDate;
+use crate::civil::{self, Ymd};
+use crate::holidays::GermanHoliday;
+use crate::regions::{supported_year_range, GermanRegion};
+
+impl Arbitrary for GermanRegion {
+    type Parameters = ();
+    type Strategy = Select<GermanRegion>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        select(GermanRegion::ALL)
+    }
+}
+
+impl Arbitrary for GermanHoliday {
+    type Parameters = ();
+    type Strategy = Select<GermanHoliday>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        select(GermanHoliday::ALL)
+    }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for GermanRegion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(GermanRegion::ALL).copied()
+    }
+}
+
+impl<'a> arbitrary::Arbitrary<'a> for GermanHoliday {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(GermanHoliday::ALL).copied()
+    }
+}
+
+fn supported_days() -> RangeInclusive<i64> {
+    let years = supported_year_range();
+    civil::days_from_civil((*years.start(), 1, 1))..=civil::days_from_civil((*years.end(), 12, 31))
+}
+
+fn ymd_from_days(days: i64) -> Ymd {
+    civil::civil_from_days(days).expect("supported dates fit into an i32 year")
+}
+
+/// Strategy generating dates within `supported_year_range()` of any `CalendarDate` type.
+pub fn supported_date<D: CalendarDate + Debug>() -> impl Strategy<Value = D> {
+    supported_days().prop_filter_map("date not representable", |days| {
+        let (year, month, day) = ymd_from_days(days);
+        D::from_ymd(year, month, day)
+    })
+}
+
+/// Generates a date within `supported_year_range()` from unstructured data, e.g. in a fuzz target.
+pub fn arbitrary_supported_date<D: CalendarDate>(u: &mut Unstructured<'_>) -> arbitrary::Result<D> {
+    let (year, month, day) = ymd_from_days(u.int_in_range(supported_days())?);
+    D::from_ymd(year, month, day).ok_or(arbitrary::Error::IncorrectFormat)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use proptest::prelude::*;
+
+    proptest! {
+    #[test]
+    fn supported_dates(date in supported_date::<NaiveDate>(), region: GermanRegion) {
+        prop_assert!(region.try_holiday_from_date(date).is_ok());
+    }
+    }
+
+    #[test]
+    fn arbitrary_from_unstructured() {
+        let mut u = Unstructured::new(&[0xff; 16]);
+        let region: GermanRegion = u.arbitrary().unwrap();
+        assert!(GermanRegion::ALL.contains(&region));
+        let date: NaiveDate = arbitrary_supported_date(&mut u).unwrap();
+        assert!(supported_year_range().contains(&chrono::Datelike::year(&date)));
+    }
+}
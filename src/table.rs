@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+use core::ops::RangeInclusive;
+
+use crate::holidays::GermanHoliday;
+use crate::one_off::ONE_OFF_HOLIDAYS;
+use crate::regions::GermanRegion;
+
+/// A single public holiday occurrence in a region, see `holiday_table`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HolidayTableRow {
+    pub region: GermanRegion,
+    pub year: i32,
+    pub date: NaiveDate,
+    pub holiday: GermanHoliday,
+    /// True for one-off holidays like Reformationstag 2017, see `ONE_OFF_HOLIDAYS`.
+    pub one_off: bool,
+    /// First year in which the holiday applies in the region, see `HolidayRule::valid_from`.
+    ///
+    /// `None` for holidays applying since before 1990, `year` for one-off holidays.
+    pub valid_from: Option<i32>,
+    /// Last year in which the holiday applies in the region, see `HolidayRule::valid_to`.
+    ///
+    /// `None` for holidays which still apply, `year` for one-off holidays.
+    pub valid_to: Option<i32>,
+}
+
+/// Returns all public holidays of all regions within the given years,
+/// ordered by region, then by date.
+///
+/// One-off holidays like Reformationstag 2017 are included.
+/// Years before 1990 do not contribute any rows.
+pub fn holiday_table(years: RangeInclusive<i32>) -> Vec<HolidayTableRow> {
+    GermanRegion::ALL
+        .iter()
+        .flat_map(|&region| {
+            years
+                .clone()
+                .flat_map(move |year| holiday_table_rows(region, year))
+        })
+        .collect()
+}
+
+/// The rows of `holiday_table` for a single region and year.
+pub(crate) fn holiday_table_rows(
+    region: GermanRegion,
+    year: i32,
+) -> impl Iterator<Item = HolidayTableRow> {
+    region.iter_holiday_dates(year).map(move |(date, holiday)| {
+        let one_off = ONE_OFF_HOLIDAYS.iter().any(|one_off| {
+            one_off.year == year && one_off.region == region && one_off.holiday == holiday
+        });
+        let (valid_from, valid_to) = if one_off {
+            (Some(year), Some(year))
+        } else {
+            region
+                .rules()
+                .find(|rule| rule.holiday == holiday && rule.applies_in(year))
+                .map_or((None, None), |rule| (rule.valid_from, rule.valid_to))
+        };
+        HolidayTableRow {
+            region,
+            year,
+            date,
+            holiday,
+            one_off,
+            valid_from,
+            valid_to,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn table_contains_all_regions_and_years() {
+        let table = holiday_table(2016..=2018);
+        for &region in GermanRegion::ALL {
+            for year in 2016..=2018 {
+                let rows = table
+                    .iter()
+                    .filter(|row| row.region == region && row.year == year)
+                    .count();
+                assert_eq!(region.holidays_in_year(year).len(), rows);
+            }
+        }
+        assert!(table.contains(&HolidayTableRow {
+            region: Bayern,
+            year: 2017,
+            date: NaiveDate::from_ymd_opt(2017, 10, 31).unwrap(),
+            holiday: Reformationstag,
+            one_off: true,
+            valid_from: Some(2017),
+            valid_to: Some(2017),
+        }));
+        assert!(table.contains(&HolidayTableRow {
+            region: Hamburg,
+            year: 2018,
+            date: NaiveDate::from_ymd_opt(2018, 10, 31).unwrap(),
+            holiday: Reformationstag,
+            one_off: false,
+            valid_from: Some(2017),
+            valid_to: None,
+        }));
+        assert!(table
+            .iter()
+            .filter(|row| row.holiday == Neujahr)
+            .all(|row| !row.one_off && row.valid_from.is_none() && row.valid_to.is_none()));
+    }
+}
@@ -0,0 +1,38 @@
+use core::convert::TryFrom;
+
+use crate::calendar_date::CalendarDate;
+
+impl CalendarDate for time::Date {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        let month = time::Month::try_from(u8::try_from(month).ok()?).ok()?;
+        time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        (
+            self.year(),
+            u32::from(u8::from(self.month())),
+            u32::from(self.day()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+    use crate::DateExt;
+    use time::{Date, Month};
+
+    #[test]
+    fn time_date_is_holiday() {
+        let date = Date::from_calendar_date(2019, Month::October, 3).unwrap();
+        assert!(date.is_public_holiday_in(Berlin));
+        assert_eq!(Some(TagDerDeutschenEinheit), date.public_holiday_in(Berlin));
+        assert!(date.is_holiday(TagDerDeutschenEinheit));
+        assert_eq!(Some(date), TagDerDeutschenEinheit.date_as(2019));
+        assert_eq!(
+            Berlin.iter_holidays_in_year(2019).count(),
+            Berlin.iter_holiday_dates_as::<Date>(2019).count()
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+
+/// A difference between the holidays computed by this crate and an external source,
+/// see `GermanRegion::compare_holiday_dates`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Discrepancy<D> {
+    /// A public holiday computed by this crate which is missing from the external source.
+    MissingExternally(D, GermanHoliday),
+    /// A date listed by the external source which is not a public holiday according to this crate.
+    MissingLocally(D),
+}
+
+impl GermanRegion {
+    /// Compares the public holidays in the given year with dates from an external source,
+    /// e.g. a response of feiertage-api.de fetched by the caller.
+    ///
+    /// Returns an empty list if both agree.
+    pub fn compare_holiday_dates<D: CalendarDate + Clone>(
+        &self,
+        year: i32,
+        external: &[D],
+    ) -> Vec<Discrepancy<D>> {
+        let computed = self.holiday_dates_in_year_as::<D>(year);
+        let computed_dates = computed
+            .iter()
+            .map(|(date, _)| date.to_ymd())
+            .collect::<Vec<_>>();
+        let external_dates = external.iter().map(D::to_ymd).collect::<Vec<_>>();
+        let missing_locally = external
+            .iter()
+            .filter(|date| !computed_dates.contains(&date.to_ymd()))
+            .cloned()
+            .map(Discrepancy::MissingLocally);
+        computed
+            .into_iter()
+            .filter(|(date, _)| !external_dates.contains(&date.to_ymd()))
+            .map(|(date, holiday)| Discrepancy::MissingExternally(date, holiday))
+            .chain(missing_locally)
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn compare_holiday_dates() {
+        let mut external = Hessen
+            .holiday_dates_in_year(2024)
+            .into_iter()
+            .map(|(date, _)| date)
+            .filter(|&date| date != NaiveDate::from_ymd_opt(2024, 5, 30).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Hessen.compare_holiday_dates(2024, &external),
+            vec![Discrepancy::MissingExternally(
+                NaiveDate::from_ymd_opt(2024, 5, 30).unwrap(),
+                Fronleichnam
+            )]
+        );
+        external.push(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap());
+        assert!(Hessen.compare_holiday_dates(2024, &external).contains(
+            &Discrepancy::MissingLocally(NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+use crate::calendar_date::CalendarDate;
+use crate::holidays::GermanHoliday;
+use crate::regions::GermanRegion;
+use crate::rules::data_version;
+
+/// The public holidays of a region in a single year, precomputed for caching,
+/// e.g. serialized with serde or archived with rkyv for zero-copy access.
+///
+/// `data_version` records the `data_version()` the calendar was computed with,
+/// so cached calendars can be discarded once the holiday data changes.
+///
+/// Lookups use a binary search over the sorted holidays instead of recomputing any dates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+pub struct YearCalendar {
+    pub region: GermanRegion,
+    pub year: i32,
+    pub data_version: String,
+    /// Sorted by date.
+    pub holidays: Vec<YearCalendarEntry>,
+}
+
+/// A public holiday within a `YearCalendar`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+pub struct YearCalendarEntry {
+    pub month: u32,
+    pub day: u32,
+    pub holiday: GermanHoliday,
+}
+
+impl GermanRegion {
+    /// Precomputes the public holidays in the given year, see `YearCalendar`.
+    pub fn year_calendar(&self, year: i32) -> YearCalendar {
+        YearCalendar {
+            region: *self,
+            year,
+            data_version: data_version().to_owned(),
+            holidays: self
+                .iter_holiday_ymds(year)
+                .map(|((_, month, day), holiday)| YearCalendarEntry {
+                    month,
+                    day,
+                    holiday,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl YearCalendar {
+    /// True if the calendar was computed with the holiday data of this version of the crate.
+    pub fn is_current(&self) -> bool {
+        self.data_version == data_version()
+    }
+
+    /// Returns the public holiday on the given day of the year, if any.
+    pub fn holiday_on(&self, month: u32, day: u32) -> Option<GermanHoliday> {
+        let index = self
+            .holidays
+            .partition_point(|entry| (entry.month, entry.day) < (month, day));
+        self.holidays
+            .get(index)
+            .filter(|entry| entry.month == month && entry.day == day)
+            .map(|entry| entry.holiday)
+    }
+
+    /// True if the given day of the year is a public holiday.
+    pub fn is_holiday(&self, month: u32, day: u32) -> bool {
+        self.holiday_on(month, day).is_some()
+    }
+
+    /// Returns the public holiday on the given date, if any.
+    ///
+    /// Always `None` for dates outside of the calendar's year.
+    pub fn holiday_from_date<D: CalendarDate>(&self, date: &D) -> Option<GermanHoliday> {
+        let (year, month, day) = date.to_ymd();
+        if year == self.year {
+            self.holiday_on(month, day)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all public holidays and their dates in chronological order
+    /// as any supported date type.
+    pub fn iter_holiday_dates_as<D: CalendarDate>(
+        &self,
+    ) -> impl Iterator<Item = (D, GermanHoliday)> + '_ {
+        self.holidays.iter().filter_map(move |entry| {
+            D::from_ymd(self.year, entry.month, entry.day).map(|date| (date, entry.holiday))
+        })
+    }
+
+    /// Returns an iterator over all public holidays and their dates in chronological order.
+    #[cfg(feature = "chrono")]
+    pub fn iter_holiday_dates(&self) -> impl Iterator<Item = (NaiveDate, GermanHoliday)> + '_ {
+        self.iter_holiday_dates_as()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedYearCalendar {
+    /// True if the calendar was computed with the holiday data of this version of the crate.
+    pub fn is_current(&self) -> bool {
+        self.data_version == data_version()
+    }
+
+    /// Returns the public holiday on the given day of the year, if any, without deserializing the calendar.
+    pub fn holiday_on(&self, month: u32, day: u32) -> Option<GermanHoliday> {
+        let index = self.holidays.partition_point(|entry| {
+            (entry.month.to_native(), entry.day.to_native()) < (month, day)
+        });
+        self.holidays
+            .get(index)
+            .filter(|entry| entry.month == month && entry.day == day)
+            .map(|entry| {
+                rkyv::deserialize::<_, rkyv::rancor::Infallible>(&entry.holiday)
+                    .unwrap_or_else(|never| match never {})
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::holidays::GermanHoliday::*;
+    use crate::regions::GermanRegion::*;
+
+    #[test]
+    fn year_calendar() {
+        let calendar = Bayern.year_calendar(2024);
+        assert!(calendar.is_current());
+        assert_eq!(calendar.holidays.len(), 13);
+        assert_eq!(calendar.holiday_on(8, 15), Some(MariaeHimmelfahrt));
+        assert_eq!(calendar.holiday_on(8, 16), None);
+        assert!(Bayern.year_calendar(1989).holidays.is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn year_calendar_agrees_with_region() {
+        use chrono::{Datelike, NaiveDate};
+
+        for &region in crate::GermanRegion::ALL {
+            let calendar = region.year_calendar(2008);
+            assert!(calendar
+                .iter_holiday_dates()
+                .eq(region.iter_holiday_dates(2008)));
+            for date in NaiveDate::from_ymd_opt(2008, 1, 1).unwrap().iter_days() {
+                if date.year() > 2008 {
+                    break;
+                }
+                assert_eq!(
+                    calendar.is_holiday(date.month(), date.day()),
+                    region.is_holiday(date)
+                );
+            }
+        }
+        let calendar = Berlin.year_calendar(2024);
+        let date = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap();
+        assert_eq!(calendar.holiday_from_date(&date), Some(Frauentag));
+        assert_eq!(
+            calendar.holiday_from_date(&date.with_year(2025).unwrap()),
+            None
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_roundtrip() {
+        use super::{ArchivedYearCalendar, YearCalendar};
+        let calendar = Sachsen.year_calendar(2024);
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&calendar).unwrap();
+        let archived = rkyv::access::<ArchivedYearCalendar, rkyv::rancor::Error>(&bytes).unwrap();
+        assert!(archived.is_current());
+        assert_eq!(archived.holiday_on(11, 20), Some(BussUndBettag));
+        assert_eq!(
+            rkyv::deserialize::<YearCalendar, rkyv::rancor::Error>(archived).unwrap(),
+            calendar
+        );
+    }
+}
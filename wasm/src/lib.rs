@@ -0,0 +1,118 @@
+//! JavaScript bindings for `holiday_de`, built with wasm-bindgen.
+//!
+//! Build them with `wasm-pack build`, which also generates the TypeScript definitions.
+use chrono::NaiveDate;
+use wasm_bindgen::prelude::*;
+
+use holiday_de::{GermanHoliday, GermanRegion};
+
+/// A public holiday on a specific date.
+#[wasm_bindgen(js_name = Holiday, getter_with_clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasmHoliday {
+    /// ISO 8601 date, e.g. `2024-10-03`.
+    pub date: String,
+    /// Identifier of the holiday, e.g. `TagDerDeutschenEinheit`.
+    pub holiday: String,
+    /// German name of the holiday, e.g. `Tag der Deutschen Einheit`.
+    pub name: String,
+}
+
+impl WasmHoliday {
+    fn new(date: NaiveDate, holiday: GermanHoliday) -> Self {
+        Self {
+            date: date.to_string(),
+            holiday: format!("{:?}", holiday),
+            name: holiday.description().to_owned(),
+        }
+    }
+}
+
+/// Returns the ISO 3166-2 codes of all regions, e.g. `DE-BY`.
+#[wasm_bindgen]
+pub fn regions() -> Vec<String> {
+    GermanRegion::ALL
+        .iter()
+        .map(|region| region.iso_code().to_owned())
+        .collect()
+}
+
+/// Returns true if the given date (`YYYY-MM-DD`) is a public holiday in the given region.
+///
+/// Regions are parsed like `GermanRegion::from_str`, e.g. `DE-BY`, `NRW` or `Thüringen`.
+#[wasm_bindgen(js_name = isHoliday)]
+pub fn is_holiday(date_iso: &str, region: &str) -> Result<bool, JsError> {
+    holiday_on(date_iso, region).map(|holiday| holiday.is_some())
+}
+
+/// Returns the public holiday on the given date (`YYYY-MM-DD`) in the given region, if any.
+#[wasm_bindgen(js_name = holidayOn)]
+pub fn holiday_on(date_iso: &str, region: &str) -> Result<Option<WasmHoliday>, JsError> {
+    let date = parse_date(date_iso).map_err(|e| JsError::new(&e))?;
+    let region = parse_region(region).map_err(|e| JsError::new(&e))?;
+    Ok(region
+        .holiday_from_date(date)
+        .map(|holiday| WasmHoliday::new(date, holiday)))
+}
+
+/// Returns all public holidays of the given region in the given year, sorted by date.
+#[wasm_bindgen(js_name = holidaysInYear)]
+pub fn holidays_in_year(region: &str, year: i32) -> Result<Vec<WasmHoliday>, JsError> {
+    let region = parse_region(region).map_err(|e| JsError::new(&e))?;
+    Ok(region
+        .iter_holiday_dates(year)
+        .map(|(date, holiday)| WasmHoliday::new(date, holiday))
+        .collect())
+}
+
+/// Returns the first public holiday of the given region on or after the given date (`YYYY-MM-DD`).
+#[wasm_bindgen(js_name = nextHoliday)]
+pub fn next_holiday(region: &str, from_iso: &str) -> Result<Option<WasmHoliday>, JsError> {
+    let region = parse_region(region).map_err(|e| JsError::new(&e))?;
+    let from = parse_date(from_iso).map_err(|e| JsError::new(&e))?;
+    Ok(region
+        .iter_holidays_from(from)
+        .next()
+        .map(|(date, holiday)| WasmHoliday::new(date, holiday)))
+}
+
+// errors are converted into `JsError`s only at the boundary,
+// since creating them outside of a JavaScript environment panics
+fn parse_region(input: &str) -> Result<GermanRegion, String> {
+    input
+        .parse()
+        .map_err(|_| format!("unknown region: {}", input))
+}
+
+fn parse_date(input: &str) -> Result<NaiveDate, String> {
+    input
+        .parse()
+        .map_err(|_| format!("invalid date, expected YYYY-MM-DD: {}", input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exported_functions() {
+        assert_eq!(regions().len(), 16);
+        assert!(is_holiday("2024-10-31", "DE-SN").unwrap());
+        assert!(!is_holiday("2024-10-31", "Bayern").unwrap());
+        assert_eq!(
+            next_holiday("BE", "2025-03-01").unwrap(),
+            Some(WasmHoliday {
+                date: "2025-03-08".to_owned(),
+                holiday: "Frauentag".to_owned(),
+                name: "Frauentag".to_owned(),
+            })
+        );
+        assert_eq!(holidays_in_year("nrw", 2024).unwrap().len(), 11);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(parse_region("Bavaria").is_err());
+        assert!(parse_date("31.10.2024").is_err());
+    }
+}